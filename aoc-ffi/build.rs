@@ -0,0 +1,15 @@
+//! Generate `aoc.h` from the `extern "C"` items of the crate into `OUT_DIR`.
+//! The copy in `include/` is committed for C callers, and a test checks it
+//! matches this one.
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out = std::env::var("OUT_DIR").unwrap();
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", dir)).unwrap();
+    cbindgen::generate_with_config(&dir, config)
+        .expect("cannot generate the C header")
+        .write_to_file(format!("{}/aoc.h", out));
+}
@@ -0,0 +1,52 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated from src/lib.rs by the build script, do not edit. */
+
+#include <stddef.h>
+#include <stdint.h>
+
+// Result of `aoc_solve`.
+typedef enum AocStatus {
+  // The answer is in the output buffer.
+  AOC_STATUS_OK = 0,
+  // The input or the output buffer is NULL, or the output buffer is empty.
+  AOC_STATUS_NULL_POINTER = 1,
+  // No solver for the day.
+  AOC_STATUS_UNKNOWN_DAY = 2,
+  // The part is neither 1 nor 2.
+  AOC_STATUS_UNKNOWN_PART = 3,
+  // The input is not UTF-8.
+  AOC_STATUS_INVALID_UTF8 = 4,
+  // The solver rejected the input, the message says where.
+  AOC_STATUS_PARSE_ERROR = 5,
+  // The solver panicked, e.g. on an input with no answer.
+  AOC_STATUS_PANIC = 6,
+  // The answer did not fit, the output buffer holds its beginning.
+  AOC_STATUS_BUFFER_TOO_SMALL = 7,
+  // The input parsed but has no answer, e.g. one too large to compute.
+  AOC_STATUS_NO_ANSWER = 8,
+} AocStatus;
+
+// Solve `part` (1 or 2) of `day` on the `input_len` bytes at `input`.
+//
+// On success the answer is written to `out_buf`, which holds `out_len`
+// bytes, as a NUL-terminated string. On failure `out_buf` gets a message
+// describing the error instead, truncated to fit.
+//
+// # Safety
+//
+// `input` must point to `input_len` readable bytes (it may be NULL when
+// `input_len` is 0), and `out_buf` to `out_len` writable bytes.
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char *out_buf,
+                         size_t out_len);
+
+// A static description of a status returned by `aoc_solve`, which the
+// caller must not free. Takes an `int` so any value is safe to pass.
+const char *aoc_status_message(int status);
+
+#endif  /* AOC_H */
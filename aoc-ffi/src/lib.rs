@@ -0,0 +1,154 @@
+//! C interface to the solvers, see `include/aoc.h` (a copy of the header the
+//! build script generates) and the C program in `tests/solve.c`.
+//!
+//! Every day is solved with its first registered variant. The answer, or a
+//! message saying what went wrong, is written to the caller's buffer as a
+//! NUL-terminated string.
+
+use aoc::registry;
+use common::solution::Answer;
+use std::ffi::{c_char, c_int, CStr};
+use std::panic::{self, AssertUnwindSafe};
+
+/// Result of `aoc_solve`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer is in the output buffer.
+    Ok = 0,
+    /// The input or the output buffer is NULL, or the output buffer is empty.
+    NullPointer = 1,
+    /// No solver for the day.
+    UnknownDay = 2,
+    /// The part is neither 1 nor 2.
+    UnknownPart = 3,
+    /// The input is not UTF-8.
+    InvalidUtf8 = 4,
+    /// The solver rejected the input, the message says where.
+    ParseError = 5,
+    /// The solver panicked, e.g. on an input with no answer.
+    Panic = 6,
+    /// The answer did not fit, the output buffer holds its beginning.
+    BufferTooSmall = 7,
+    /// The input parsed but has no answer, e.g. one too large to compute.
+    NoAnswer = 8,
+}
+
+const STATUSES: [AocStatus; 9] = [
+    AocStatus::Ok,
+    AocStatus::NullPointer,
+    AocStatus::UnknownDay,
+    AocStatus::UnknownPart,
+    AocStatus::InvalidUtf8,
+    AocStatus::ParseError,
+    AocStatus::Panic,
+    AocStatus::BufferTooSmall,
+    AocStatus::NoAnswer,
+];
+
+impl AocStatus {
+    fn message(self) -> &'static CStr {
+        match self {
+            AocStatus::Ok => c"ok",
+            AocStatus::NullPointer => c"null pointer or empty output buffer",
+            AocStatus::UnknownDay => c"no solver for this day",
+            AocStatus::UnknownPart => c"the part must be 1 or 2",
+            AocStatus::InvalidUtf8 => c"the input is not UTF-8",
+            AocStatus::ParseError => c"the input cannot be parsed",
+            AocStatus::Panic => c"the solver panicked",
+            AocStatus::BufferTooSmall => c"the output buffer is too small for the answer",
+            AocStatus::NoAnswer => c"the input has no answer",
+        }
+    }
+}
+
+/// Solve `part` of `day`, with the first registered variant of the day.
+pub fn solve(day: u8, part: u8, input: &[u8]) -> Result<Answer, (AocStatus, String)> {
+    let Some(solution) = registry::solutions(day, None).into_iter().next() else {
+        return Err((AocStatus::UnknownDay, format!("no solver for day {}", day)));
+    };
+    if !registry::PARTS.contains(&part) {
+        return Err((
+            AocStatus::UnknownPart,
+            format!("part {} does not exist, it must be 1 or 2", part),
+        ));
+    }
+    let input = std::str::from_utf8(input).map_err(|e| {
+        (
+            AocStatus::InvalidUtf8,
+            format!("the input is not UTF-8: {}", e),
+        )
+    })?;
+    // unwinding into C is undefined behaviour
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let parsed = solution
+            .parse(input)
+            .map_err(|e| (AocStatus::ParseError, e.to_string()))?;
+        solution
+            .solve(&parsed, part)
+            .map_err(|e| (AocStatus::NoAnswer, e.to_string()))
+    }))
+    .map_err(|e| (AocStatus::Panic, aoc::report::panic_message(e.as_ref())))?
+}
+
+/// Copy `text` into the `len` bytes at `buf` as a NUL-terminated string,
+/// truncating it if needed. Whether it all fitted.
+unsafe fn write(text: &str, buf: *mut c_char, len: usize) -> bool {
+    let n = text.len().min(len - 1);
+    std::ptr::copy_nonoverlapping(text.as_ptr(), buf as *mut u8, n);
+    *buf.add(n) = 0;
+    n == text.len()
+}
+
+/// Solve `part` (1 or 2) of `day` on the `input_len` bytes at `input`.
+///
+/// On success the answer is written to `out_buf`, which holds `out_len`
+/// bytes, as a NUL-terminated string. On failure `out_buf` gets a message
+/// describing the error instead, truncated to fit.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes (it may be NULL when
+/// `input_len` is 0), and `out_buf` to `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+) -> AocStatus {
+    if out_buf.is_null() || out_len == 0 || (input.is_null() && input_len > 0) {
+        return AocStatus::NullPointer;
+    }
+    let input = if input_len == 0 {
+        &[][..]
+    } else {
+        std::slice::from_raw_parts(input, input_len)
+    };
+    match solve(day, part, input) {
+        Ok(answer) => {
+            if write(&answer.to_string(), out_buf, out_len) {
+                AocStatus::Ok
+            } else {
+                AocStatus::BufferTooSmall
+            }
+        }
+        Err((status, message)) => {
+            write(&message, out_buf, out_len);
+            status
+        }
+    }
+}
+
+/// A static description of a status returned by `aoc_solve`, which the
+/// caller must not free. Takes an `int` so any value is safe to pass.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: c_int) -> *const c_char {
+    STATUSES
+        .iter()
+        .find(|s| **s as c_int == status)
+        .map_or(c"unknown status", |s| s.message())
+        .as_ptr()
+}
@@ -0,0 +1,98 @@
+use aoc_ffi::{aoc_solve, AocStatus};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+/// Build `libaoc_ffi` and return its directory. `cargo test` only builds the
+/// rlib, the shared library goes next to the `deps` of the test binary.
+fn build_library() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    let dir = exe.parent().unwrap().parent().unwrap().to_path_buf();
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut build = Command::new(cargo);
+    build
+        .current_dir(DIR)
+        .args(["build", "--package", "aoc-ffi"]);
+    if dir.ends_with("release") {
+        build.arg("--release");
+    }
+    let target = dir.parent().unwrap();
+    assert!(build
+        .arg("--target-dir")
+        .arg(target)
+        .status()
+        .unwrap()
+        .success());
+    dir
+}
+
+#[test]
+fn committed_header_matches_the_generated_one() {
+    let generated = concat!(env!("OUT_DIR"), "/aoc.h");
+    let committed = std::fs::read_to_string(Path::new(DIR).join("include/aoc.h")).unwrap();
+    assert!(
+        committed == include_str!(concat!(env!("OUT_DIR"), "/aoc.h")),
+        "include/aoc.h is out of date, update it with `cp {} {}/include/aoc.h`",
+        generated,
+        DIR
+    );
+}
+
+#[test]
+fn c_program_solves_every_day() {
+    let out = Path::new(env!("CARGO_TARGET_TMPDIR")).join("solve");
+    let lib = build_library();
+    let cc = std::env::var_os("CC").unwrap_or_else(|| "cc".into());
+    let status = Command::new(cc)
+        .arg(Path::new(DIR).join("tests/solve.c"))
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg(format!("-I{}/include", DIR))
+        .arg(format!("-L{}", lib.display()))
+        .arg(format!("-Wl,-rpath,{}", lib.display()))
+        .arg("-laoc_ffi")
+        .arg("-o")
+        .arg(&out)
+        .status()
+        .expect("cannot run the C compiler, set $CC");
+    assert!(status.success(), "tests/solve.c does not compile");
+
+    let run = Command::new(&out).output().unwrap();
+    let stdout = String::from_utf8_lossy(&run.stdout);
+    assert!(run.status.success(), "{}", stdout);
+    assert_eq!(stdout.matches("ok ").count(), 8 * 2 + 6, "{}", stdout);
+}
+
+fn solve(day: u8, part: u8, input: &str, out_len: usize) -> (AocStatus, String) {
+    let mut out = vec![0xffu8; out_len];
+    let status = unsafe {
+        aoc_solve(
+            day,
+            part,
+            input.as_ptr(),
+            input.len(),
+            out.as_mut_ptr().cast(),
+            out.len(),
+        )
+    };
+    let end = out
+        .iter()
+        .position(|&b| b == 0)
+        .expect("no terminating NUL");
+    (status, String::from_utf8_lossy(&out[..end]).into_owned())
+}
+
+#[test]
+fn errors_come_with_a_message() {
+    let (status, message) = solve(1, 1, "3 4\n4\n", 100);
+    assert_eq!(status, AocStatus::ParseError);
+    assert!(message.starts_with("line 2, column"), "{}", message);
+
+    let (status, message) = solve(6, 1, "..\n..\n", 100);
+    assert_eq!(status, AocStatus::Panic);
+    assert!(!message.is_empty());
+
+    let (status, message) = solve(1, 1, "3 4\n4 3\n", 1);
+    assert_eq!((status, message.as_str()), (AocStatus::BufferTooSmall, ""));
+}
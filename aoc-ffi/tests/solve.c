@@ -0,0 +1,173 @@
+/* Solve the example of every day through the C interface, then check the
+ * error codes. Built and run by tests/c.rs. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "aoc.h"
+
+struct example {
+    uint8_t day;
+    const char *input;
+    const char *answers[2];
+};
+
+static const struct example EXAMPLES[] = {
+    {1,
+         "3   4\n"
+         "4   3\n"
+         "2   5\n"
+         "1   3\n"
+         "3   9\n"
+         "3   3\n",
+     {"11", "31"}},
+    {2,
+         "7 6 4 2 1\n"
+         "1 2 7 8 9\n"
+         "9 7 6 2 1\n"
+         "1 3 2 4 5\n"
+         "8 6 4 4 1\n"
+         "1 3 6 7 9\n",
+     {"2", "4"}},
+    {3,
+         "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n",
+     {"161", "48"}},
+    {4,
+         "MMMSXXMASM\n"
+         "MSAMXMSMSA\n"
+         "AMXSXMAAMM\n"
+         "MSAMASMSMX\n"
+         "XMASAMXAMM\n"
+         "XXAMMXXAMA\n"
+         "SMSMSASXSS\n"
+         "SAXAMASAAA\n"
+         "MAMMMXMMMM\n"
+         "MXMXAXMASX\n",
+     {"18", "9"}},
+    {5,
+         "47|53\n"
+         "97|13\n"
+         "97|61\n"
+         "97|47\n"
+         "75|29\n"
+         "61|13\n"
+         "75|53\n"
+         "29|13\n"
+         "97|29\n"
+         "53|29\n"
+         "61|53\n"
+         "97|53\n"
+         "61|29\n"
+         "47|13\n"
+         "75|47\n"
+         "97|75\n"
+         "47|61\n"
+         "75|61\n"
+         "47|29\n"
+         "75|13\n"
+         "53|13\n"
+         "\n"
+         "75,47,61,53,29\n"
+         "97,61,53,29,13\n"
+         "75,29,13\n"
+         "75,97,47,61,53\n"
+         "61,13,29\n"
+         "97,13,75,29,47\n",
+     {"143", "123"}},
+    {6,
+         "....#.....\n"
+         ".........#\n"
+         "..........\n"
+         "..#.......\n"
+         ".......#..\n"
+         "..........\n"
+         ".#..^.....\n"
+         "........#.\n"
+         "#.........\n"
+         "......#...\n",
+     {"41", "6"}},
+    {7,
+         "190: 10 19\n"
+         "3267: 81 40 27\n"
+         "83: 17 5\n"
+         "156: 15 6\n"
+         "7290: 6 8 6 15\n"
+         "161011: 16 10 13\n"
+         "192: 17 8 14\n"
+         "21037: 9 7 18 13\n"
+         "292: 11 6 16 20\n",
+     {"3749", "11387"}},
+    {8,
+         "............\n"
+         "........0...\n"
+         ".....0......\n"
+         ".......0....\n"
+         "....0.......\n"
+         "......A.....\n"
+         "............\n"
+         "............\n"
+         "........A...\n"
+         ".........A..\n"
+         "............\n"
+         "............\n",
+     {"14", "34"}},
+};
+
+static int failures = 0;
+
+static void expect(const char *what, AocStatus status, AocStatus expected,
+                   const char *out, const char *answer) {
+    if (status != expected || (answer != NULL && strcmp(out, answer) != 0)) {
+        printf("FAIL %s: %s (%d) \"%s\", expected %s (%d) \"%s\"\n", what,
+               aoc_status_message(status), status, out,
+               aoc_status_message(expected), expected, answer ? answer : "");
+        failures++;
+    } else {
+        printf("ok   %s: %s\n", what, out);
+    }
+}
+
+int main(void) {
+    char out[256];
+    char what[64];
+
+    for (size_t i = 0; i < sizeof EXAMPLES / sizeof EXAMPLES[0]; i++) {
+        const struct example *e = &EXAMPLES[i];
+        for (uint8_t part = 1; part <= 2; part++) {
+            AocStatus status = aoc_solve(e->day, part, (const uint8_t *)e->input,
+                                         strlen(e->input), out, sizeof out);
+            snprintf(what, sizeof what, "day %u part %u", e->day, part);
+            expect(what, status, AOC_STATUS_OK, out, e->answers[part - 1]);
+        }
+    }
+
+    const char *input = EXAMPLES[0].input;
+    const uint8_t *bytes = (const uint8_t *)input;
+    AocStatus status;
+
+    status = aoc_solve(1, 1, bytes, strlen(input), NULL, 0);
+    expect("no output buffer", status, AOC_STATUS_NULL_POINTER, "", NULL);
+
+    status = aoc_solve(26, 1, bytes, strlen(input), out, sizeof out);
+    expect("unknown day", status, AOC_STATUS_UNKNOWN_DAY, out, "no solver for day 26");
+
+    status = aoc_solve(1, 3, bytes, strlen(input), out, sizeof out);
+    expect("unknown part", status, AOC_STATUS_UNKNOWN_PART, out, NULL);
+
+    status = aoc_solve(1, 1, (const uint8_t *)"1 \xff\n", 4, out, sizeof out);
+    expect("invalid UTF-8", status, AOC_STATUS_INVALID_UTF8, out, NULL);
+
+    status = aoc_solve(1, 1, (const uint8_t *)"1 x\n", 4, out, sizeof out);
+    expect("parse error", status, AOC_STATUS_PARSE_ERROR, out, NULL);
+
+    status = aoc_solve(1, 1, bytes, strlen(input), out, 2);
+    expect("small buffer", status, AOC_STATUS_BUFFER_TOO_SMALL, out, "1");
+
+    if (strcmp(aoc_status_message(-1), "unknown status") != 0) {
+        printf("FAIL message of an unknown status: %s\n", aoc_status_message(-1));
+        failures++;
+    }
+
+    printf("%d failure(s)\n", failures);
+    return failures != 0;
+}
@@ -0,0 +1,164 @@
+//! Verified answers, keyed by day, part and a hash of the input they were
+//! computed from, so a refactor that changes a result is caught.
+
+use common::solution::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Store used when `$AOC_ANSWERS` is not set.
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.json");
+
+/// `$AOC_ANSWERS`, or [`DEFAULT_PATH`].
+pub fn default_path() -> PathBuf {
+    std::env::var_os("AOC_ANSWERS")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_PATH))
+}
+
+/// Stable 64-bit FNV-1a hash of `input` in hex. Trailing whitespace is
+/// ignored, so saving the file in another editor keeps its answers.
+pub fn input_hash(input: &str) -> String {
+    let hash = input
+        .trim_end()
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+            (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{:016x}", hash)
+}
+
+/// How a fresh answer compares to the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+pub enum Status {
+    Correct,
+    Regressed {
+        expected: Answer,
+    },
+    /// Nothing was recorded for this day, part and input.
+    Unknown,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Correct => write!(f, "correct"),
+            Status::Regressed { expected } => write!(f, "regressed (expected {})", expected),
+            Status::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    day: u8,
+    part: u8,
+    input: String,
+    answer: Answer,
+}
+
+#[derive(Debug)]
+pub enum StoreError {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Format {
+        path: PathBuf,
+        source: serde_json::Error,
+    },
+}
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StoreError::Io { path, source } => {
+                write!(f, "cannot access {}: {}", path.display(), source)
+            }
+            StoreError::Format { path, source } => {
+                write!(f, "{} is not an answer store: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StoreError {}
+
+/// Answers recorded as verified, saved as a JSON list sorted by day and part.
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u8, u8, String), Answer>,
+}
+
+impl AnswerStore {
+    /// Read the store at `path`, empty when the file does not exist yet.
+    pub fn load(path: &Path) -> Result<Self, StoreError> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(source) => {
+                return Err(StoreError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })
+            }
+        };
+        let entries: Vec<Entry> =
+            serde_json::from_str(&text).map_err(|source| StoreError::Format {
+                path: path.to_path_buf(),
+                source,
+            })?;
+        Ok(AnswerStore {
+            answers: entries
+                .into_iter()
+                .map(|e| ((e.day, e.part, e.input), e.answer))
+                .collect(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), StoreError> {
+        let entries = self
+            .answers
+            .iter()
+            .map(|((day, part, input), answer)| Entry {
+                day: *day,
+                part: *part,
+                input: input.clone(),
+                answer: answer.clone(),
+            })
+            .collect::<Vec<_>>();
+        let text = serde_json::to_string_pretty(&entries).unwrap() + "\n";
+        std::fs::write(path, text).map_err(|source| StoreError::Io {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: &str) -> Option<&Answer> {
+        self.answers.get(&(day, part, input_hash.to_string()))
+    }
+
+    /// Record `answer` as verified, returning the answer it replaces.
+    pub fn record(
+        &mut self,
+        day: u8,
+        part: u8,
+        input_hash: &str,
+        answer: Answer,
+    ) -> Option<Answer> {
+        self.answers
+            .insert((day, part, input_hash.to_string()), answer)
+    }
+
+    pub fn check(&self, day: u8, part: u8, input_hash: &str, answer: &Answer) -> Status {
+        match self.get(day, part, input_hash) {
+            None => Status::Unknown,
+            Some(expected) if expected == answer => Status::Correct,
+            Some(expected) => Status::Regressed {
+                expected: expected.clone(),
+            },
+        }
+    }
+}
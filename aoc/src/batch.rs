@@ -0,0 +1,73 @@
+//! Run a day over every input in a directory, e.g. inputs collected from
+//! several people to check the solutions are general.
+
+use crate::report::{self, Record};
+use common::solution::DynSolution;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileRecord {
+    pub file: PathBuf,
+    #[serde(flatten)]
+    pub record: Record,
+}
+
+/// Regular files of `dir`, hidden ones excepted, sorted by name.
+pub fn inputs(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = vec![];
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if entry.file_type()?.is_file() && !hidden {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Run each of `solutions` on each of `files`, `jobs` files at a time.
+/// Unreadable files, parse errors and panics are failed records, and the
+/// records come back in the order of `files`. Panics still go through the
+/// panic hook, which is left to the caller.
+pub fn run(
+    solutions: &[&'static dyn DynSolution],
+    parts: &[u8],
+    files: &[PathBuf],
+    jobs: usize,
+) -> Vec<FileRecord> {
+    let next = AtomicUsize::new(0);
+    let done = Mutex::new(vec![]);
+
+    std::thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, files.len().max(1)) {
+            s.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(file) = files.get(i) else {
+                    break;
+                };
+                let buf = std::fs::read_to_string(file)
+                    .map_err(|e| format!("cannot read {}: {}", file.display(), e));
+                let records = solutions
+                    .iter()
+                    .flat_map(|s| report::run(*s, parts, &buf))
+                    .collect::<Vec<_>>();
+                done.lock().unwrap().push((i, records));
+            });
+        }
+    });
+
+    let mut done = done.into_inner().unwrap();
+    done.sort_by_key(|(i, _)| *i);
+    done.into_iter()
+        .flat_map(|(i, records)| {
+            records.into_iter().map(move |record| FileRecord {
+                file: files[i].clone(),
+                record,
+            })
+        })
+        .collect()
+}
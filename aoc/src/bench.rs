@@ -0,0 +1,173 @@
+//! Compare the algorithm variants of a day on the same inputs, scaled up to
+//! see how the difference grows with the input size.
+
+use crate::generate::guard_leaves;
+use crate::registry::{self, PARTS};
+use crate::report::panic_message;
+use common::parse::ParseError;
+use common::solution::Answer;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+pub struct Measurement {
+    pub variant: &'static str,
+    /// The answer, or why there is none, e.g. the message the solver
+    /// panicked with.
+    pub answer: Result<Answer, String>,
+    /// Median time of one solve, parsing excluded.
+    pub median: Duration,
+    pub samples: usize,
+}
+
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub scale: usize,
+    /// Size of the scaled input in bytes.
+    pub input_len: usize,
+    pub measurements: Vec<Measurement>,
+}
+
+impl Comparison {
+    /// Whether every variant found the same answer.
+    pub fn agrees(&self) -> bool {
+        self.measurements
+            .windows(2)
+            .all(|w| w[0].answer == w[1].answer)
+    }
+
+    pub fn fastest(&self) -> Duration {
+        self.measurements
+            .iter()
+            .filter(|m| m.answer.is_ok())
+            .map(|m| m.median)
+            .min()
+            .unwrap_or_default()
+    }
+}
+
+/// Days that keep more than one implementation.
+pub fn days_with_variants() -> Vec<u8> {
+    registry::days()
+        .into_iter()
+        .filter(|&d| registry::solutions(d, None).len() > 1)
+        .collect()
+}
+
+/// Grow `input` of `day` about `k` times while keeping it a valid puzzle.
+pub fn scale(day: u8, input: &str, k: usize) -> String {
+    let lines = input.lines().collect::<Vec<_>>();
+    match day {
+        // grids: repeat every row k times side by side
+        4 | 8 => lines.iter().map(|l| l.repeat(k) + "\n").collect(),
+        // the same, with the guard in the first copy only, unless it no
+        // longer walks off the map: then the copies are left empty, which
+        // it crosses in a straight line
+        6 => {
+            let copies = k.saturating_sub(1);
+            let tiled = lines
+                .iter()
+                .map(|l| l.to_string() + &l.replace('^', ".").repeat(copies) + "\n")
+                .collect::<String>();
+            if guard_escapes(&tiled) {
+                return tiled;
+            }
+            lines
+                .iter()
+                .map(|l| l.to_string() + &".".repeat(l.len() * copies) + "\n")
+                .collect()
+        }
+        // rules once, then the updates k times
+        5 => {
+            let split = lines
+                .iter()
+                .position(|l| l.trim().is_empty())
+                .unwrap_or(lines.len());
+            let (rules, updates) = lines.split_at(split);
+            let updates = updates
+                .iter()
+                .filter(|l| !l.trim().is_empty())
+                .map(|u| format!("{}\n", u))
+                .collect::<String>();
+            rules.join("\n") + "\n\n" + &updates.repeat(k)
+        }
+        _ => (lines.join("\n") + "\n").repeat(k),
+    }
+}
+
+/// Whether the guard of a day 6 map walks off it. A map without a guard
+/// passes, its solvers report that.
+fn guard_escapes(map: &str) -> bool {
+    let grid = map.lines().flat_map(str::chars).collect::<Vec<_>>();
+    let width = map.lines().next().map_or(0, str::len);
+    grid.iter()
+        .position(|&c| c == '^')
+        .is_none_or(|start| guard_leaves(&grid, width, start))
+}
+
+/// Time `f` until `budget` is spent (at least 3 runs), returning its last
+/// result, the median run time and the number of runs. A failure ends the
+/// measurement early, the time of the runs that failed is not counted.
+fn measure(
+    budget: Duration,
+    mut f: impl FnMut() -> Result<Answer, String>,
+) -> (Result<Answer, String>, Duration, usize) {
+    let mut answer = f();
+    let mut times = vec![];
+    let start = Instant::now();
+    while answer.is_ok() && (times.len() < 3 || (start.elapsed() < budget && times.len() < 10_000))
+    {
+        let t = Instant::now();
+        answer = f();
+        times.push(t.elapsed());
+    }
+    times.sort_unstable();
+    let median = times.get(times.len() / 2).copied().unwrap_or_default();
+    (answer, median, times.len())
+}
+
+/// Benchmark every variant of `day` on `input` scaled by each of `scales`.
+pub fn compare(
+    day: u8,
+    input: &str,
+    scales: &[usize],
+    budget: Duration,
+) -> Result<Vec<Comparison>, ParseError> {
+    let solutions = registry::solutions(day, None);
+    let mut comparisons = vec![];
+    for &k in scales {
+        let scaled = scale(day, input, k);
+        let parsed = solutions
+            .iter()
+            .map(|s| s.parse(&scaled))
+            .collect::<Result<Vec<_>, _>>()?;
+        for part in PARTS {
+            let measurements = solutions
+                .iter()
+                .zip(parsed.iter())
+                .map(|(s, p)| {
+                    // a solver that panics fails its measurement, not the bench
+                    let (answer, median, samples) = measure(budget, || {
+                        panic::catch_unwind(AssertUnwindSafe(|| s.solve(p, part)))
+                            .map_err(|e| panic_message(e.as_ref()))
+                            .and_then(|answer| answer.map_err(|e| e.to_string()))
+                    });
+                    Measurement {
+                        variant: s.variant(),
+                        answer,
+                        median,
+                        samples,
+                    }
+                })
+                .collect();
+            comparisons.push(Comparison {
+                day,
+                part,
+                scale: k,
+                input_len: scaled.len(),
+                measurements,
+            });
+        }
+    }
+    Ok(comparisons)
+}
@@ -0,0 +1,166 @@
+//! Differential testing: feed the same random inputs to every variant of a
+//! day, and shrink any input they disagree on down to a small reproducer.
+
+use crate::generate::{self, Options};
+use crate::registry::{self, PARTS};
+use crate::report::panic_message;
+use common::solution::Answer;
+use std::collections::HashSet;
+use std::panic::{self, AssertUnwindSafe};
+
+/// Variants of a day that gave different answers to the same input.
+#[derive(Debug)]
+pub struct Mismatch {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// Answer of each variant, or the message it panicked with.
+    pub answers: Vec<(&'static str, Result<Answer, String>)>,
+}
+
+/// Run every variant of `day` on `input` and report the first part on which
+/// they disagree. Inputs the parsers reject, or that have no answer (a day 6
+/// guard that never leaves the map, day 5 pages the rules leave unordered),
+/// are never a mismatch.
+pub fn check(day: u8, input: &str) -> Option<Mismatch> {
+    if !admissible(day, input) {
+        return None;
+    }
+    let solutions = registry::solutions(day, None);
+    let parsed = solutions
+        .iter()
+        .map(|s| s.parse(input).ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    for part in PARTS {
+        let answers = solutions
+            .iter()
+            .zip(parsed.iter())
+            .map(|(s, p)| {
+                let answer = panic::catch_unwind(AssertUnwindSafe(|| s.solve(p, part)))
+                    .map_err(|e| panic_message(e.as_ref()))
+                    .and_then(|answer| answer.map_err(|e| e.to_string()));
+                (s.variant(), answer)
+            })
+            .collect::<Vec<_>>();
+        if answers.windows(2).any(|w| w[0].1 != w[1].1) {
+            return Some(Mismatch {
+                day,
+                part,
+                input: input.to_string(),
+                answers,
+            });
+        }
+    }
+    None
+}
+
+fn admissible(day: u8, input: &str) -> bool {
+    match day {
+        // the puzzle only defines the middle page when the rules order every
+        // pair of pages of an update
+        5 => day5::parse(input).is_ok_and(|input| {
+            let rules = input.rules.iter().collect::<HashSet<_>>();
+            input.updates.iter().all(|update| {
+                update.iter().enumerate().all(|(i, a)| {
+                    update[i + 1..]
+                        .iter()
+                        .all(|b| rules.contains(&(*a, *b)) || rules.contains(&(*b, *a)))
+                })
+            })
+        }),
+        6 => {
+            let width = input.lines().next().map_or(0, str::len);
+            let cells = input.lines().flat_map(str::chars).collect::<Vec<_>>();
+            let rectangular = width > 0 && input.lines().all(|l| l.len() == width);
+            match cells.iter().position(|&c| c == '^') {
+                Some(start) if rectangular => generate::guard_leaves(&cells, width, start),
+                _ => false,
+            }
+        }
+        _ => true,
+    }
+}
+
+/// Smallest input found by deleting runs of lines, then `,` or space
+/// separated items within each line, then runs of columns when every line has
+/// the same width, for which `fails` still holds.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+    let mut lines = shrink_by(lines, |lines| fails(&join(lines)));
+
+    for i in 0..lines.len() {
+        let sep = if lines[i].contains(',') { "," } else { " " };
+        let items = lines[i].split(sep).map(str::to_string).collect::<Vec<_>>();
+        let items = shrink_by(items, |items| {
+            let mut candidate = lines.clone();
+            candidate[i] = items.join(sep);
+            fails(&join(&candidate))
+        });
+        lines[i] = items.join(sep);
+    }
+
+    let width = lines.first().map_or(0, String::len);
+    if width > 1 && lines.iter().all(|l| l.len() == width && l.is_ascii()) {
+        let columns = (0..width)
+            .map(|i| lines.iter().map(|l| l.as_bytes()[i]).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let rows = |columns: &[Vec<u8>]| {
+            (0..lines.len())
+                .map(|r| columns.iter().map(|c| c[r] as char).collect::<String>())
+                .collect::<Vec<_>>()
+        };
+        let columns = shrink_by(columns, |columns| fails(&join(&rows(columns))));
+        return join(&rows(&columns));
+    }
+    join(&lines)
+}
+
+fn join(lines: &[String]) -> String {
+    lines.iter().map(|l| format!("{}\n", l)).collect()
+}
+
+/// Remove chunks of `items`, halving the chunk size whenever no chunk of the
+/// current size can go, as long as `fails` holds for what remains.
+fn shrink_by<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunk = (items.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut i = 0;
+        while i < items.len() {
+            let end = (i + chunk).min(items.len());
+            let candidate = [&items[..i], &items[end..]].concat();
+            if !candidate.is_empty() && fails(&candidate) {
+                items = candidate;
+                removed = true;
+            } else {
+                i += chunk;
+            }
+        }
+        if !removed {
+            if chunk == 1 {
+                return items;
+            }
+            chunk /= 2;
+        }
+    }
+}
+
+/// Check `runs` generated inputs of `day`, with seeds counting up from
+/// `opts.seed`, and return the first mismatch shrunk to a minimal input.
+///
+/// The variants may panic on purpose-built inputs and the shrinker runs them
+/// many times, each panic going through the panic hook, which is left to the
+/// caller.
+pub fn search(day: u8, opts: Options, runs: u64) -> Option<Mismatch> {
+    let found = (opts.seed..opts.seed + runs).find_map(|seed| {
+        let input = generate::generate(day, Options { seed, ..opts })?;
+        check(day, &input)
+    });
+    found.map(|m| {
+        let input = shrink(&m.input, |t| {
+            check(day, t).is_some_and(|n| n.part == m.part)
+        });
+        check(day, &input).unwrap_or(m)
+    })
+}
@@ -0,0 +1,109 @@
+//! Overlays of the grid days, for `aoc draw`.
+
+use std::fmt;
+
+use common::geometry::Direction;
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::render::{Layer, PALETTE};
+
+/// A grid and the layers drawn over it.
+pub type Drawing = (Grid<char>, Vec<Layer>);
+
+/// Days with something to draw.
+pub const DAYS: [u8; 3] = [4, 6, 8];
+
+#[derive(Debug)]
+pub enum DrawError {
+    Parse(ParseError),
+    /// A day 6 map without a `^`.
+    NoGuard,
+    /// A day 6 map whose guard never leaves, so no obstacle can be counted.
+    GuardLoops,
+}
+
+impl DrawError {
+    /// The error, quoting the offending line of `input` for parse errors.
+    pub fn render(&self, input: &str) -> String {
+        match self {
+            DrawError::Parse(e) => e.render(input),
+            e => e.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DrawError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DrawError::Parse(e) => e.fmt(f),
+            DrawError::NoGuard => write!(f, "the map has no guard '^'"),
+            DrawError::GuardLoops => write!(f, "the guard never leaves the map"),
+        }
+    }
+}
+
+impl std::error::Error for DrawError {}
+
+impl From<ParseError> for DrawError {
+    fn from(e: ParseError) -> Self {
+        DrawError::Parse(e)
+    }
+}
+
+/// The grid of `day` and the overlays of `part`, `None` when the day is not a
+/// grid puzzle.
+pub fn layers(day: u8, input: &str, part: u8) -> Option<Result<Drawing, DrawError>> {
+    let grid = match day {
+        4 => day4::parse(input),
+        6 => day6::parse(input),
+        8 => day8::parse(input),
+        _ => return None,
+    };
+    Some(grid.map_err(DrawError::from).and_then(|grid| {
+        if day == 6 {
+            let Some((x, y)) = grid.find(&'^') else {
+                return Err(DrawError::NoGuard);
+            };
+            if part != 1 && day6::out_of_grid2(&(x, y, Direction::Up), &grid).is_none() {
+                return Err(DrawError::GuardLoops);
+            }
+        }
+        let layers = match (day, part) {
+            (4, 1) => vec![Layer::new("XMAS", PALETTE[0], day4::xmas_cells(&grid))],
+            (4, _) => vec![Layer::new("X-MAS", PALETTE[0], day4::x_mas_cells(&grid))],
+            (6, p) => {
+                let mut layers = vec![Layer::new(
+                    "guard path",
+                    PALETTE[1],
+                    day6::guard_path(&grid),
+                )];
+                if p != 1 {
+                    layers.push(Layer::new(
+                        "loop obstacles",
+                        PALETTE[5],
+                        day6::loop_obstacles(&grid),
+                    ));
+                }
+                layers
+            }
+            (_, p) => {
+                let mut antinodes = day8::antinodes(&grid, p != 1)
+                    .into_iter()
+                    .collect::<Vec<_>>();
+                antinodes.sort_by_key(|(freq, _)| *freq);
+                antinodes
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, (freq, points))| {
+                        Layer::auto(
+                            format!("antinodes of {}", freq),
+                            i,
+                            points.into_iter().filter_map(|p| p.to_index()),
+                        )
+                    })
+                    .collect()
+            }
+        };
+        Ok((grid, layers))
+    }))
+}
@@ -0,0 +1,87 @@
+//! Examples embedded in the `dayN.md` writeups.
+//!
+//! An example is a plain fenced block preceded by an annotation comment giving
+//! the answers the writeup states for it:
+//!
+//! ```text
+//! <!-- example: part1 = 41, part2 = 6 -->
+//! ```
+//!
+//! An optional `variant = NAME` restricts the example to one algorithm variant,
+//! for writeups that show an input the other variants are known to get wrong.
+//! Inside the block, ` -> ` starts a comment running to the end of the line.
+
+use std::path::PathBuf;
+
+pub struct Example {
+    /// 1-based line of the annotation in the writeup.
+    pub line: usize,
+    pub input: String,
+    /// `(part, answer)` pairs.
+    pub answers: Vec<(u8, String)>,
+    pub variant: Option<String>,
+}
+
+/// Path of the writeup of `day`, not every day has one.
+pub fn writeup(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{}", day))
+        .join(format!("day{}.md", day))
+}
+
+pub fn extract(markdown: &str) -> Result<Vec<Example>, String> {
+    let mut examples = vec![];
+    let mut lines = markdown.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let Some(annotation) = line
+            .trim()
+            .strip_prefix("<!-- example:")
+            .and_then(|l| l.strip_suffix("-->"))
+        else {
+            continue;
+        };
+
+        let mut answers = vec![];
+        let mut variant = None;
+        for field in annotation.split(',') {
+            let Some((key, value)) = field.split_once('=') else {
+                return Err(format!(
+                    "line {}: expected `key = value`, found `{}`",
+                    i + 1,
+                    field.trim()
+                ));
+            };
+            let (key, value) = (key.trim(), value.trim().to_string());
+            match key.strip_prefix("part").map(|p| p.parse::<u8>()) {
+                Some(Ok(part)) => answers.push((part, value)),
+                _ if key == "variant" => variant = Some(value),
+                _ => return Err(format!("line {}: unknown key `{}`", i + 1, key)),
+            }
+        }
+
+        if !matches!(lines.next(), Some((_, fence)) if fence.trim_end() == "```") {
+            return Err(format!(
+                "line {}: annotation is not followed by a ``` block",
+                i + 1
+            ));
+        }
+        let mut input = String::new();
+        for (_, line) in lines.by_ref() {
+            if line.trim_end() == "```" {
+                break;
+            }
+            let line = line.split_once(" -> ").map_or(line, |(l, _)| l);
+            input.push_str(line.trim_end());
+            input.push('\n');
+        }
+
+        examples.push(Example {
+            line: i + 1,
+            input,
+            answers,
+            variant,
+        });
+    }
+    Ok(examples)
+}
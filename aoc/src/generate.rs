@@ -0,0 +1,354 @@
+//! Seeded generators of synthetic puzzle inputs, one per day.
+//!
+//! Every generator is a pure function of its [`Options`]: the same seed always
+//! produces the same input, and the output is accepted by the day's parser.
+
+/// Small deterministic PRNG (SplitMix64), so the inputs for a seed never
+/// change with a dependency upgrade.
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `lo..=hi`.
+    pub fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.next_u64() % (hi - lo + 1)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0, items.len() as u64 - 1) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0, i as u64) as usize);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    /// Number of lines, or side of the grid for grid puzzles.
+    pub size: usize,
+    /// Day specific ratio in `0.0..=1.0`, see [`generate`].
+    pub density: f64,
+    pub seed: u64,
+}
+
+/// Default `size` and `density` of `day`, about as large as a real input.
+pub fn defaults(day: u8) -> Options {
+    let (size, density) = match day {
+        1 => (1000, 0.3),
+        2 => (1000, 0.5),
+        3 => (700, 0.5),
+        4 => (140, 0.05),
+        5 => (200, 1.0),
+        6 => (130, 0.02),
+        7 => (850, 0.4),
+        _ => (50, 0.05),
+    };
+    Options {
+        size,
+        density,
+        seed: 0,
+    }
+}
+
+/// Generate an input of `day`. What `density` controls depends on the day:
+/// 1. share of right-hand values copied from the left list,
+/// 2. share of safe reports,
+/// 3. share of well formed `mul` instructions among the noise,
+/// 4. share of cells covered by planted `XMAS` words,
+/// 5. share of the ordering rules kept between pages,
+/// 6. share of obstacle cells,
+/// 7. share of equations built to be solvable,
+/// 8. share of antenna cells.
+pub fn generate(day: u8, opts: Options) -> Option<String> {
+    let mut rng = Rng::new(opts.seed);
+    let size = opts.size.max(1);
+    let density = opts.density.clamp(0.0, 1.0);
+    let text = match day {
+        1 => day1(&mut rng, size, density),
+        2 => day2(&mut rng, size, density),
+        3 => day3(&mut rng, size, density),
+        4 => day4(&mut rng, size, density),
+        5 => day5(&mut rng, size, density),
+        6 => day6(&mut rng, size, density),
+        7 => day7(&mut rng, size, density),
+        8 => day8(&mut rng, size, density),
+        _ => return None,
+    };
+    Some(text)
+}
+
+fn day1(rng: &mut Rng, size: usize, density: f64) -> String {
+    let firsts = (0..size)
+        .map(|_| rng.range(10_000, 99_999))
+        .collect::<Vec<_>>();
+    let mut out = String::new();
+    for first in firsts.iter() {
+        let second = if rng.chance(density) {
+            *rng.pick(&firsts)
+        } else {
+            rng.range(10_000, 99_999)
+        };
+        out.push_str(&format!("{}   {}\n", first, second));
+    }
+    out
+}
+
+fn day2(rng: &mut Rng, size: usize, density: f64) -> String {
+    let mut out = String::new();
+    for _ in 0..size {
+        let len = rng.range(5, 8) as usize;
+        let ascending = rng.chance(0.5);
+        let mut level = rng.range(10, 90) as i64;
+        let mut report = vec![level];
+        for _ in 1..len {
+            let step = rng.range(1, 3) as i64;
+            level += if ascending { step } else { -step };
+            report.push(level);
+        }
+        if !rng.chance(density) {
+            // break one level, sometimes so badly that no single removal helps
+            for _ in 0..rng.range(1, 2) {
+                let i = rng.range(0, len as u64 - 1) as usize;
+                report[i] += rng.range(0, 6) as i64 - 3;
+            }
+        }
+        let report = report
+            .iter()
+            .map(|l| l.max(&1).to_string())
+            .collect::<Vec<_>>();
+        out.push_str(&report.join(" "));
+        out.push('\n');
+    }
+    out
+}
+
+fn day3(rng: &mut Rng, size: usize, density: f64) -> String {
+    const NOISE: [&str; 12] = [
+        "mul[3,7]",
+        "mul(32,64]",
+        "mul ( 2 , 4 )",
+        "?mul(",
+        "from()",
+        "select()",
+        "what()",
+        "%&",
+        "how(",
+        "+",
+        "'",
+        "mul(4*",
+    ];
+    let mut out = String::new();
+    for _ in 0..size {
+        if rng.chance(density) {
+            out.push_str(&format!("mul({},{})", rng.range(1, 999), rng.range(1, 999)));
+        } else if rng.chance(0.1) {
+            out.push_str(if rng.chance(0.5) { "do()" } else { "don't()" });
+        } else {
+            out.push_str(rng.pick::<&str>(&NOISE));
+        }
+    }
+    out.push('\n');
+    out
+}
+
+fn day4(rng: &mut Rng, size: usize, density: f64) -> String {
+    const LETTERS: [char; 4] = ['X', 'M', 'A', 'S'];
+    const DIRS: [(isize, isize); 8] = [
+        (-1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+        (1, 0),
+        (1, -1),
+        (0, -1),
+        (-1, -1),
+    ];
+    let mut grid = (0..size * size)
+        .map(|_| *rng.pick(&LETTERS))
+        .collect::<Vec<_>>();
+    // each word covers 4 cells
+    let words = (density * (size * size) as f64 / 4.0) as usize;
+    for _ in 0..words {
+        let (r, c) = (rng.range(0, size as u64 - 1), rng.range(0, size as u64 - 1));
+        let (dr, dc) = *rng.pick(&DIRS);
+        let end = (r as isize + 3 * dr, c as isize + 3 * dc);
+        if end.0 < 0 || end.1 < 0 || end.0 >= size as isize || end.1 >= size as isize {
+            continue;
+        }
+        for (k, ch) in "XMAS".chars().enumerate() {
+            let (i, j) = (r as isize + k as isize * dr, c as isize + k as isize * dc);
+            grid[i as usize * size + j as usize] = ch;
+        }
+    }
+    grid.chunks(size)
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn day5(rng: &mut Rng, size: usize, density: f64) -> String {
+    // a hidden total order of the pages, rules are pairs consistent with it
+    let mut pages = (10..100).collect::<Vec<u64>>();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+
+    let mut rules = Vec::new();
+    for i in 0..pages.len() {
+        for j in i + 1..pages.len() {
+            if rng.chance(density) {
+                rules.push(format!("{}|{}", pages[i], pages[j]));
+            }
+        }
+    }
+    if rules.is_empty() {
+        // the parser needs at least one rule to tell the two sections apart
+        rules.push(format!("{}|{}", pages[0], pages[1]));
+    }
+    // the rules are listed in no particular order in real inputs
+    rng.shuffle(&mut rules);
+    let mut out = rules.join("\n") + "\n\n";
+
+    for _ in 0..size {
+        let len = rng.range(2, 11) as usize * 2 + 1;
+        let mut idx = (0..pages.len()).collect::<Vec<_>>();
+        rng.shuffle(&mut idx);
+        idx.truncate(len);
+        if rng.chance(0.5) {
+            idx.sort_unstable();
+        }
+        let update = idx
+            .iter()
+            .map(|&i| pages[i].to_string())
+            .collect::<Vec<_>>();
+        out.push_str(&update.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn day6(rng: &mut Rng, size: usize, density: f64) -> String {
+    // re-roll until the guard walks off the map, part 1 has no answer otherwise
+    for attempt in 0.. {
+        let mut grid = (0..size * size)
+            .map(|_| if rng.chance(density) { '#' } else { '.' })
+            .collect::<Vec<_>>();
+        let start = rng.range(0, (size * size) as u64 - 1) as usize;
+        grid[start] = '^';
+        if attempt >= 100 {
+            // dense maps rarely let the guard out, clear the way straight up
+            for row in 0..start / size {
+                grid[row * size + start % size] = '.';
+            }
+        }
+        if guard_leaves(&grid, size, start) {
+            return grid
+                .chunks(size)
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+        }
+    }
+    unreachable!()
+}
+
+/// Whether the guard starting at `start`, facing up, walks off the map of
+/// `width` columns stored row by row in `grid`.
+pub(crate) fn guard_leaves(grid: &[char], width: usize, start: usize) -> bool {
+    const DIRS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+    let (width, height) = (width as isize, (grid.len() / width) as isize);
+    let mut seen = vec![[false; 4]; grid.len()];
+    let (mut r, mut c, mut d) = (start as isize / width, start as isize % width, 0);
+    loop {
+        let idx = (r * width + c) as usize;
+        if seen[idx][d] {
+            return false;
+        }
+        seen[idx][d] = true;
+        let (nr, nc) = (r + DIRS[d].0, c + DIRS[d].1);
+        if nr < 0 || nc < 0 || nr >= height || nc >= width {
+            return true;
+        }
+        if grid[(nr * width + nc) as usize] == '#' {
+            d = (d + 1) % 4;
+        } else {
+            (r, c) = (nr, nc);
+        }
+    }
+}
+
+fn day7(rng: &mut Rng, size: usize, density: f64) -> String {
+    // puzzle values have up to 15 digits, so the answer, their sum, stays
+    // well within an i64
+    const MAX_VALUE: i64 = 10i64.pow(15) - 1;
+    let mut out = String::new();
+    for _ in 0..size {
+        // as in puzzle inputs: 2 to 12 numbers of 1 to 3 digits, so most
+        // combinations of operators overflow an i64 on the longer lines
+        let len = rng.range(2, 12) as usize;
+        let numbers = (0..len)
+            .map(|_| {
+                let digits = rng.range(1, 3) as u32;
+                rng.range(10u64.pow(digits - 1), 10u64.pow(digits) - 1) as i64
+            })
+            .collect::<Vec<_>>();
+        let reachable = if rng.chance(density) {
+            numbers[1..]
+                .iter()
+                .try_fold(numbers[0], |v, &n| match rng.range(0, 2) {
+                    0 => v.checked_add(n),
+                    1 => v.checked_mul(n),
+                    _ => v
+                        .checked_mul(10i64.pow(n.ilog10() + 1))
+                        .and_then(|v| v.checked_add(n)),
+                })
+        } else {
+            None
+        };
+        let value = reachable.filter(|&v| v <= MAX_VALUE).unwrap_or_else(|| {
+            let product = numbers
+                .iter()
+                .try_fold(1i64, |p, &n| p.checked_mul(n))
+                .unwrap_or(i64::MAX);
+            rng.range(1, product.clamp(2, MAX_VALUE) as u64) as i64
+        });
+        let numbers = numbers.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        out.push_str(&format!("{}: {}\n", value, numbers.join(" ")));
+    }
+    out
+}
+
+fn day8(rng: &mut Rng, size: usize, density: f64) -> String {
+    const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let frequencies = FREQUENCIES.chars().collect::<Vec<_>>();
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.chance(density) {
+                        *rng.pick(&frequencies)
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
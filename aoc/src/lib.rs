@@ -0,0 +1,11 @@
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod differential;
+pub mod draw;
+pub mod examples;
+pub mod generate;
+pub mod registry;
+pub mod report;
+pub mod serve;
+pub mod watch;
@@ -0,0 +1,965 @@
+use aoc::answers::{self, AnswerStore, Status};
+use aoc::batch::{self, FileRecord};
+use aoc::bench::{self, Comparison};
+use aoc::differential;
+use aoc::draw;
+use aoc::generate;
+use aoc::registry::{self, PARTS};
+use aoc::report::{self, Outcome, Record};
+use aoc::serve;
+use aoc::watch;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use common::input;
+use common::render;
+use common::solution::Answer;
+use common::trace;
+use day1::metrics;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code 2024 solvers")]
+struct Cli {
+    /// Store of verified answers (defaults to $AOC_ANSWERS, then answers.json at the repository root)
+    #[arg(long, global = true)]
+    answers: Option<PathBuf>,
+    /// Trace what the solvers decide on stderr, -vv for every step
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one or more solvers and print a summary table
+    Run {
+        /// Only run this day (all days when omitted)
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Only run this part (both parts when omitted)
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Only run this algorithm variant, e.g. `simple` or `topological` for day 5
+        #[arg(long)]
+        variant: Option<String>,
+        /// Puzzle input file (falls back to $AOC_DAY{N}_INPUT, the day's own input, then stdin)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Output format, the JSON ones report parse and solve times separately
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        #[command(flatten)]
+        unequal: UnequalArgs,
+    },
+    /// Record answers as verified for an input, so later runs can spot regressions
+    Record {
+        #[arg(short, long)]
+        day: u8,
+        /// Only record this part (both parts when omitted)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Variant computing the answer (the day's first one when omitted)
+        #[arg(long)]
+        variant: Option<String>,
+        /// Puzzle input file (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Record this answer instead of computing it, needs --part
+        #[arg(long, requires = "part")]
+        answer: Option<String>,
+    },
+    /// Run a day over every input file in a directory, in parallel
+    Batch {
+        #[arg(short, long)]
+        day: u8,
+        /// Directory of puzzle inputs
+        dir: PathBuf,
+        /// Only run this part (both parts when omitted)
+        #[arg(short, long)]
+        part: Option<u8>,
+        /// Only run this algorithm variant (all of the day's when omitted)
+        #[arg(long)]
+        variant: Option<String>,
+        /// Inputs solved at the same time (the number of CPUs when omitted)
+        #[arg(short, long)]
+        jobs: Option<usize>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Draw a grid day with the cells behind its answer highlighted
+    Draw {
+        #[arg(short, long)]
+        day: u8,
+        /// Part whose cells to highlight
+        #[arg(short, long, default_value_t = 1)]
+        part: u8,
+        /// Puzzle input file (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Write an SVG file instead of drawing in the terminal
+        #[arg(long)]
+        svg: Option<PathBuf>,
+    },
+    /// List the pairs behind day 1 part 1, largest distance first
+    Pairs {
+        /// Puzzle input file (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        unequal: UnequalArgs,
+        /// Only list the pairs with the largest distances
+        #[arg(long)]
+        top: Option<usize>,
+    },
+    /// Compare every pair of columns of a day 1 input, which may have more than two
+    Matrix {
+        /// Puzzle input file (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        unequal: UnequalArgs,
+        /// Part 1 total distance, or part 2 similarity score
+        #[arg(long, value_enum, default_value_t = MatrixOf::Distance)]
+        metric: MatrixOf,
+        /// Print CSV instead of a table
+        #[arg(long)]
+        csv: bool,
+    },
+    /// Score the day 1 pairing with other metrics, and against the optimal assignment
+    Metrics {
+        /// Puzzle input file (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        #[command(flatten)]
+        unequal: UnequalArgs,
+        /// Exponent of the |distance|^p pair cost, sorting is not optimal below 1
+        #[arg(long, default_value_t = 0.5)]
+        exponent: f64,
+    },
+    /// Re-run a day whenever its input or source files change
+    Watch {
+        #[arg(short, long)]
+        day: u8,
+        /// Puzzle input file (same fallbacks as `run`, but not stdin)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// How often to look for changes, in milliseconds
+        #[arg(long, default_value_t = 500)]
+        interval_ms: u64,
+    },
+    /// Serve the solvers over HTTP: GET /days, POST /solve/{day}/{part}
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = "127.0.0.1")]
+        bind: String,
+        /// Port to listen on, 0 picks a free one
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+    },
+    /// Compare the algorithm variants of the days that have several
+    Bench {
+        /// Only benchmark this day (every day with variants when omitted)
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Puzzle input to scale up (same fallbacks as `run`)
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Scale factors applied to the input
+        #[arg(long, value_delimiter = ',', default_value = "1,2,4,8")]
+        scales: Vec<usize>,
+        /// Time spent measuring each variant at each scale, in milliseconds
+        #[arg(long, default_value_t = 200)]
+        budget_ms: u64,
+    },
+    /// Print a synthetic input for a day
+    Generate {
+        #[arg(short, long)]
+        day: u8,
+        /// Number of lines, or side of the grid (defaults to about a real input)
+        #[arg(long)]
+        size: Option<usize>,
+        /// Day specific ratio in 0..=1, e.g. the share of obstacles on day 6
+        #[arg(long)]
+        density: Option<f64>,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Check that the variants of a day agree on generated inputs
+    Diff {
+        /// Only check this day (every day with variants when omitted)
+        #[arg(short, long)]
+        day: Option<u8>,
+        /// Number of inputs to try per day
+        #[arg(long, default_value_t = 200)]
+        runs: u64,
+        /// Size of each input, small inputs shrink faster
+        #[arg(long, default_value_t = 12)]
+        size: usize,
+        /// First seed, the following runs use the next ones
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// A table for humans
+    Text,
+    /// One JSON array of records
+    Json,
+    /// One JSON record per line
+    Ndjson,
+}
+
+/// How day 1 columns of different lengths are evened out.
+#[derive(Args)]
+struct UnequalArgs {
+    /// What to do when some day 1 lines lack their last numbers
+    #[arg(long, value_enum, default_value_t = Unequal::Error)]
+    unequal: Unequal,
+    /// Value the shorter columns are padded with
+    #[arg(long, default_value_t = 0, allow_negative_numbers = true)]
+    pad_value: i64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Unequal {
+    /// Reject the input
+    Error,
+    /// Drop the numbers past the end of the shortest column
+    Truncate,
+    /// Fill the shorter columns up with --pad-value
+    Pad,
+}
+
+impl UnequalArgs {
+    fn policy(&self) -> day1::Unequal {
+        match self.unequal {
+            Unequal::Error => day1::Unequal::Error,
+            Unequal::Truncate => day1::Unequal::Truncate,
+            Unequal::Pad => day1::Unequal::Pad(self.pad_value),
+        }
+    }
+}
+
+/// Parse a day 1 input for the commands that look into its columns.
+fn day1_columns(input: Option<&Path>, unequal: &UnequalArgs) -> day1::Input {
+    let buf = match input::resolve(day1::DAY, input, day1::DEFAULT_INPUT) {
+        Ok(i) => i.text,
+        Err(e) => {
+            eprintln!("[day 1] {}", e);
+            std::process::exit(1);
+        }
+    };
+    day1::parse_with(&buf, unequal.policy()).unwrap_or_else(|e| {
+        eprintln!("[day 1] {}", e.render(&buf));
+        std::process::exit(1);
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum MatrixOf {
+    Distance,
+    Similarity,
+}
+
+fn print_table(records: &[Record]) {
+    println!(
+        "{:>3}  {:>4}  {:<12}  {:>16}  {:>12}  Status",
+        "Day", "Part", "Variant", "Answer", "Time"
+    );
+    for r in records {
+        match &r.outcome {
+            Outcome::Solved {
+                answer,
+                parse,
+                solve,
+            } => println!(
+                "{:>3}  {:>4}  {:<12}  {:>16}  {:>12}  {}",
+                r.day,
+                r.part,
+                r.variant,
+                answer.to_string(),
+                format!("{:.2?}", *parse + *solve),
+                r.status.as_ref().map_or(String::new(), |s| s.to_string())
+            ),
+            Outcome::Failed { .. } => println!(
+                "{:>3}  {:>4}  {:<12}  {:>16}",
+                r.day, r.part, r.variant, "error"
+            ),
+        }
+    }
+
+    // errors span several lines, print them once per day after the table
+    let mut reported = vec![];
+    for r in records {
+        if let Outcome::Failed { error } = &r.outcome {
+            if !reported.contains(&(r.day, error)) {
+                reported.push((r.day, error));
+                eprintln!("\n[day {}] {}", r.day, error);
+            }
+        }
+    }
+}
+
+fn print_records(records: &[Record], format: Format) {
+    match format {
+        Format::Text => print_table(records),
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).unwrap()),
+        Format::Ndjson => {
+            for r in records {
+                println!("{}", serde_json::to_string(r).unwrap());
+            }
+        }
+    }
+}
+
+fn print_comparisons(comparisons: &[Comparison]) {
+    println!(
+        "{:>3}  {:>4}  {:>5}  {:>9}  {:<12}  {:>12}  {:>8}  {:>16}",
+        "Day", "Part", "Scale", "Bytes", "Variant", "Median", "Relative", "Answer"
+    );
+    for c in comparisons {
+        let fastest = c.fastest().as_secs_f64();
+        for m in &c.measurements {
+            match &m.answer {
+                Ok(answer) => println!(
+                    "{:>3}  {:>4}  {:>5}  {:>9}  {:<12}  {:>12}  {:>7.2}x  {:>16}",
+                    c.day,
+                    c.part,
+                    c.scale,
+                    c.input_len,
+                    m.variant,
+                    format!("{:.2?}", m.median),
+                    m.median.as_secs_f64() / fastest,
+                    answer.to_string()
+                ),
+                Err(_) => println!(
+                    "{:>3}  {:>4}  {:>5}  {:>9}  {:<12}  {:>12}  {:>8}  {:>16}",
+                    c.day, c.part, c.scale, c.input_len, m.variant, "", "", "error"
+                ),
+            }
+        }
+        if !c.agrees() {
+            println!("     ^ variants disagree on day {} part {}", c.day, c.part);
+        }
+    }
+
+    // as in the run table, each error once per day after the table
+    let mut reported = vec![];
+    for c in comparisons {
+        for m in &c.measurements {
+            if let Err(error) = &m.answer {
+                if !reported.contains(&(c.day, error)) {
+                    reported.push((c.day, error));
+                    eprintln!("\n[day {}] {}", c.day, error);
+                }
+            }
+        }
+    }
+}
+
+fn print_batch(records: &[FileRecord], format: Format) {
+    match format {
+        Format::Json => println!("{}", serde_json::to_string_pretty(records).unwrap()),
+        Format::Ndjson => {
+            for r in records {
+                println!("{}", serde_json::to_string(r).unwrap());
+            }
+        }
+        Format::Text => {
+            let name = |r: &FileRecord| {
+                r.file
+                    .file_name()
+                    .map_or(r.file.display().to_string(), |n| n.to_string_lossy().into())
+            };
+            let width = records
+                .iter()
+                .map(|r| name(r).len())
+                .max()
+                .unwrap_or(0)
+                .max(4);
+            println!(
+                "{:<width$}  {:>4}  {:<12}  {:>16}  {:>12}  Status",
+                "File", "Part", "Variant", "Answer", "Time"
+            );
+            for r in records {
+                let FileRecord { record, .. } = r;
+                match &record.outcome {
+                    Outcome::Solved {
+                        answer,
+                        parse,
+                        solve,
+                    } => println!(
+                        "{:<width$}  {:>4}  {:<12}  {:>16}  {:>12}  {}",
+                        name(r),
+                        record.part,
+                        record.variant,
+                        answer.to_string(),
+                        format!("{:.2?}", *parse + *solve),
+                        record
+                            .status
+                            .as_ref()
+                            .map_or(String::new(), |s| s.to_string())
+                    ),
+                    Outcome::Failed { .. } => println!(
+                        "{:<width$}  {:>4}  {:<12}  {:>16}",
+                        name(r),
+                        record.part,
+                        record.variant,
+                        "error"
+                    ),
+                }
+            }
+
+            // as in `run`, errors follow the table, once per file
+            let mut reported = vec![];
+            for r in records {
+                if let Outcome::Failed { error } = &r.record.outcome {
+                    if !reported.contains(&(&r.file, error)) {
+                        reported.push((&r.file, error));
+                        eprintln!("\n[{}] {}", name(r), error);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn print_pairings(pairs: &[day1::Pairing], top: Option<usize>) {
+    let total = pairs.iter().map(|p| p.distance).sum::<i128>();
+    let share = |distance: i128| {
+        if total == 0 {
+            0.0
+        } else {
+            distance as f64 / total as f64 * 100.0
+        }
+    };
+    let shown = &pairs[..top.unwrap_or(pairs.len()).min(pairs.len())];
+
+    println!(
+        "{:>12}  {:>6}  {:>12}  {:>6}  {:>10}  {:>6}",
+        "Left", "Line", "Right", "Line", "Distance", "Share"
+    );
+    let line = |no: Option<usize>| no.map_or("pad".to_string(), |n| n.to_string());
+    for p in shown {
+        println!(
+            "{:>12}  {:>6}  {:>12}  {:>6}  {:>10}  {:>5.1}%",
+            p.left,
+            line(p.left_line),
+            p.right,
+            line(p.right_line),
+            p.distance,
+            share(p.distance)
+        );
+    }
+    println!("total distance {} over {} pairs", total, pairs.len());
+    if top.is_some() {
+        let sum = shown.iter().map(|p| p.distance).sum::<i128>();
+        println!(
+            "the {} largest distances add up to {} ({:.1}%)",
+            shown.len(),
+            sum,
+            share(sum)
+        );
+    }
+}
+
+/// Rows and columns are numbered after the input columns, from 1.
+fn print_matrix(matrix: &[Vec<i128>], csv: bool) {
+    let labels = (1..=matrix.len()).map(|i| i.to_string());
+    if csv {
+        println!(",{}", labels.collect::<Vec<_>>().join(","));
+        for (i, row) in matrix.iter().enumerate() {
+            let cells = row.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+            println!("{},{}", i + 1, cells.join(","));
+        }
+        return;
+    }
+    let width = matrix
+        .iter()
+        .flatten()
+        .map(|v| v.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max(6);
+    print!("{:>4}", "");
+    for label in labels {
+        print!("  {:>width$}", label);
+    }
+    println!();
+    for (i, row) in matrix.iter().enumerate() {
+        print!("{:>4}", i + 1);
+        for v in row {
+            print!("  {:>width$}", v);
+        }
+        println!();
+    }
+}
+
+fn load_answers(path: &Path) -> AnswerStore {
+    AnswerStore::load(path).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        std::process::exit(1);
+    })
+}
+
+/// Keep the default hook from printing the panics of the solvers, which the
+/// records report already. Only for commands that end the process when done.
+fn quiet_panics() {
+    std::panic::set_hook(Box::new(|_| {}));
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let answers_path = cli.answers.unwrap_or_else(answers::default_path);
+    trace::set_level(cli.verbose);
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            variant,
+            input,
+            format,
+            unequal,
+        } => {
+            let days = match day {
+                Some(d) => vec![d],
+                None => registry::days(),
+            };
+            let day1 = day1::Day1With(unequal.policy());
+            let mut note = None;
+            let parts = PARTS
+                .into_iter()
+                .filter(|&p| part.is_none_or(|q| p == q))
+                .collect::<Vec<_>>();
+
+            let mut records = vec![];
+            for day in days {
+                let solutions = registry::solutions(day, variant.as_deref());
+                if solutions.is_empty() || parts.is_empty() {
+                    eprintln!("no solver matches the given day/part/variant");
+                    std::process::exit(2);
+                }
+                // resolve each day's input once, it is shared by all of its variants
+                let buf = input::resolve(day, input.as_deref(), solutions[0].default_input())
+                    .map(|i| i.text)
+                    .map_err(|e| e.to_string());
+                for solution in solutions {
+                    // day 1 evens its columns out as --unequal says
+                    let solution = if day == day1::DAY { &day1 } else { solution };
+                    records.extend(report::run(solution, &parts, &buf));
+                }
+                if day == day1::DAY {
+                    if let Ok(Ok(parsed)) = buf.as_deref().map(|b| day1::parse_with(b, day1.0)) {
+                        note = Some(day1::unequal_note(&parsed));
+                    }
+                }
+            }
+            report::check(&mut records, &load_answers(&answers_path));
+            print_records(&records, format);
+            if let Some(note) = note {
+                // keep the JSON formats machine readable
+                match format {
+                    Format::Text => println!("{}", note),
+                    Format::Json | Format::Ndjson => eprintln!("{}", note),
+                }
+            }
+            if records.iter().any(|r| {
+                matches!(r.outcome, Outcome::Failed { .. })
+                    || matches!(r.status, Some(Status::Regressed { .. }))
+            }) {
+                std::process::exit(1);
+            }
+        }
+        Command::Record {
+            day,
+            part,
+            variant,
+            input,
+            answer,
+        } => {
+            let Some(solution) = registry::solutions(day, variant.as_deref())
+                .first()
+                .copied()
+            else {
+                eprintln!("no solver matches the given day/variant");
+                std::process::exit(2);
+            };
+            let buf = match input::resolve(day, input.as_deref(), solution.default_input()) {
+                Ok(i) => i.text,
+                Err(e) => {
+                    eprintln!("[day {}] {}", day, e);
+                    std::process::exit(1);
+                }
+            };
+            let hash = answers::input_hash(&buf);
+            let parts = PARTS
+                .into_iter()
+                .filter(|&p| part.is_none_or(|q| p == q))
+                .collect::<Vec<_>>();
+
+            let verified = match answer {
+                Some(a) => vec![(
+                    parts[0],
+                    a.parse::<i64>().map_or(Answer::Str(a), Answer::Int),
+                )],
+                None => report::run(solution, &parts, &Ok(buf))
+                    .into_iter()
+                    .map(|r| match r.outcome {
+                        Outcome::Solved { answer, .. } => (r.part, answer),
+                        Outcome::Failed { error } => {
+                            eprintln!("[day {}] {}", day, error);
+                            std::process::exit(1);
+                        }
+                    })
+                    .collect(),
+            };
+
+            let mut store = load_answers(&answers_path);
+            for (part, answer) in verified {
+                match store.record(day, part, &hash, answer.clone()) {
+                    Some(old) if old != answer => println!(
+                        "day {} part {}: {} (was {}) for input {}",
+                        day, part, answer, old, hash
+                    ),
+                    _ => println!("day {} part {}: {} for input {}", day, part, answer, hash),
+                }
+            }
+            if let Err(e) = store.save(&answers_path) {
+                eprintln!("{}", e);
+                std::process::exit(1);
+            }
+        }
+        Command::Batch {
+            day,
+            dir,
+            part,
+            variant,
+            jobs,
+            format,
+        } => {
+            let solutions = registry::solutions(day, variant.as_deref());
+            let parts = PARTS
+                .into_iter()
+                .filter(|&p| part.is_none_or(|q| p == q))
+                .collect::<Vec<_>>();
+            if solutions.is_empty() || parts.is_empty() {
+                eprintln!("no solver matches the given day/part/variant");
+                std::process::exit(2);
+            }
+            let files = batch::inputs(&dir).unwrap_or_else(|e| {
+                eprintln!("cannot list {}: {}", dir.display(), e);
+                std::process::exit(1);
+            });
+            let jobs =
+                jobs.unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+
+            quiet_panics();
+            let mut records = batch::run(&solutions, &parts, &files, jobs);
+            let store = load_answers(&answers_path);
+            for r in records.iter_mut() {
+                report::check(std::slice::from_mut(&mut r.record), &store);
+            }
+            print_batch(&records, format);
+            if records.iter().any(|r| {
+                matches!(r.record.outcome, Outcome::Failed { .. })
+                    || matches!(r.record.status, Some(Status::Regressed { .. }))
+            }) {
+                std::process::exit(1);
+            }
+        }
+        Command::Draw {
+            day,
+            part,
+            input,
+            svg,
+        } => {
+            let Some(solution) = registry::solutions(day, None)
+                .first()
+                .copied()
+                .filter(|_| draw::DAYS.contains(&day))
+            else {
+                let days = draw::DAYS.map(|d| d.to_string()).join(", ");
+                eprintln!("only the grid days can be drawn: {}", days);
+                std::process::exit(2);
+            };
+            let buf = match input::resolve(day, input.as_deref(), solution.default_input()) {
+                Ok(i) => i.text,
+                Err(e) => {
+                    eprintln!("[day {}] {}", day, e);
+                    std::process::exit(1);
+                }
+            };
+            let (grid, layers) = match draw::layers(day, &buf, part) {
+                Some(Ok(drawing)) => drawing,
+                Some(Err(e)) => {
+                    eprintln!("[day {}] {}", day, e.render(&buf));
+                    std::process::exit(1);
+                }
+                None => unreachable!("checked against draw::DAYS"),
+            };
+            match svg {
+                Some(path) => {
+                    if let Err(e) = std::fs::write(&path, render::svg(&grid, &layers)) {
+                        eprintln!("cannot write {}: {}", path.display(), e);
+                        std::process::exit(1);
+                    }
+                }
+                None => print!("{}", render::ansi(&grid, &layers)),
+            }
+        }
+        Command::Pairs {
+            input,
+            unequal,
+            top,
+        } => {
+            let parsed = day1_columns(input.as_deref(), &unequal);
+            print_pairings(&day1::pairings(&parsed), top);
+            println!("{}", day1::unequal_note(&parsed));
+        }
+        Command::Matrix {
+            input,
+            unequal,
+            metric,
+            csv,
+        } => {
+            let parsed = day1_columns(input.as_deref(), &unequal);
+            let matrix = match metric {
+                MatrixOf::Distance => day1::matrix(&parsed.columns, day1::total_distance),
+                MatrixOf::Similarity => day1::matrix(&parsed.columns, day1::similarity_score),
+            };
+            match matrix {
+                Ok(matrix) => print_matrix(&matrix, csv),
+                Err(e) => {
+                    eprintln!("[day 1] {}", e);
+                    std::process::exit(1);
+                }
+            }
+            // keep the CSV itself machine readable
+            if csv {
+                eprintln!("{}", day1::unequal_note(&parsed));
+            } else {
+                println!("{}", day1::unequal_note(&parsed));
+            }
+        }
+        Command::Metrics {
+            input,
+            unequal,
+            exponent,
+        } => {
+            if !(exponent > 0.0 && exponent.is_finite()) {
+                eprintln!("the exponent must be a positive number");
+                std::process::exit(2);
+            }
+            let parsed = day1_columns(input.as_deref(), &unequal);
+            let sorted = metrics::sorted_pairs(parsed.firsts(), parsed.seconds());
+            for metric in metrics::METRICS {
+                let score = metric.score(&sorted);
+                let score = if score.fract() == 0.0 {
+                    format!("{}", score)
+                } else {
+                    format!("{:.6}", score)
+                };
+                println!("{:<26}  {:>16}", metric.name(), score);
+            }
+
+            let cost = metrics::power_cost(exponent);
+            let optimal = metrics::optimal_assignment(parsed.firsts(), parsed.seconds(), &cost);
+            let (by_sorting, best) = (
+                metrics::cost_of(&sorted, &cost),
+                metrics::cost_of(&optimal, &cost),
+            );
+            println!(
+                "cost |d|^{}: {:.4} sorted, {:.4} optimal ({:.2}% lower)",
+                exponent,
+                by_sorting,
+                best,
+                if by_sorting > 0.0 {
+                    (1.0 - best / by_sorting) * 100.0
+                } else {
+                    0.0
+                }
+            );
+            println!("{}", day1::unequal_note(&parsed));
+        }
+        Command::Watch {
+            day,
+            input,
+            interval_ms,
+        } => {
+            let Some(solution) = registry::solutions(day, None).first().copied() else {
+                eprintln!("no solver for day {}", day);
+                std::process::exit(2);
+            };
+            let path = match input::resolve(day, input.as_deref(), solution.default_input()) {
+                Ok(input::Input {
+                    source:
+                        input::Source::Explicit(p) | input::Source::Env(p) | input::Source::Default(p),
+                    ..
+                }) => p,
+                Ok(_) => {
+                    eprintln!("[day {}] watch needs an input file, not stdin", day);
+                    std::process::exit(2);
+                }
+                Err(e) => {
+                    eprintln!("[day {}] {}", day, e);
+                    std::process::exit(1);
+                }
+            };
+
+            let mut files = watch::watched_files(day, &path);
+            let mut stamps = watch::stamps(&files);
+            let mut previous = None;
+            loop {
+                println!("== day {} on {}", day, path.display());
+                match watch::run(day, &path) {
+                    Ok(snapshot) => {
+                        for line in watch::diff(previous.as_ref(), &snapshot) {
+                            println!("{}", line);
+                        }
+                        previous = Some(snapshot);
+                    }
+                    Err(e) => eprintln!("[day {}] {}", day, e),
+                }
+
+                // poll until something changes, new source files included
+                let changed = loop {
+                    std::thread::sleep(Duration::from_millis(interval_ms));
+                    let now_files = watch::watched_files(day, &path);
+                    let now = watch::stamps(&now_files);
+                    if now_files != files || now != stamps {
+                        let changed = now_files
+                            .iter()
+                            .zip(now.iter())
+                            .find(|(f, t)| {
+                                files
+                                    .iter()
+                                    .position(|g| g == *f)
+                                    .is_none_or(|i| stamps[i] != **t)
+                            })
+                            .map(|(f, _)| f.clone());
+                        (files, stamps) = (now_files, now);
+                        break changed;
+                    }
+                };
+                match changed {
+                    Some(f) => println!("\n{} changed", f.display()),
+                    None => println!("\na file was removed"),
+                }
+            }
+        }
+        Command::Serve { bind, port } => {
+            let listener = match std::net::TcpListener::bind((bind.as_str(), port)) {
+                Ok(listener) => listener,
+                Err(e) => {
+                    eprintln!("cannot listen on {}:{}: {}", bind, port, e);
+                    std::process::exit(1);
+                }
+            };
+            match listener.local_addr() {
+                Ok(addr) => println!("listening on http://{}", addr),
+                Err(_) => println!("listening on http://{}:{}", bind, port),
+            }
+            serve::serve(listener);
+        }
+        Command::Bench {
+            day,
+            input,
+            scales,
+            budget_ms,
+        } => {
+            let days = match day {
+                Some(d) => vec![d],
+                None => bench::days_with_variants(),
+            };
+            quiet_panics();
+            let mut comparisons = vec![];
+            for day in days {
+                let Some(solution) = registry::solutions(day, None).first().copied() else {
+                    eprintln!("no solver for day {}", day);
+                    std::process::exit(2);
+                };
+                let buf = match input::resolve(day, input.as_deref(), solution.default_input()) {
+                    Ok(i) => i.text,
+                    Err(e) => {
+                        eprintln!("[day {}] {}", day, e);
+                        std::process::exit(1);
+                    }
+                };
+                match bench::compare(day, &buf, &scales, Duration::from_millis(budget_ms)) {
+                    Ok(c) => comparisons.extend(c),
+                    Err(e) => {
+                        eprintln!("[day {}] {}", day, e.render(&buf));
+                        std::process::exit(1);
+                    }
+                }
+            }
+            print_comparisons(&comparisons);
+            if comparisons
+                .iter()
+                .any(|c| c.measurements.iter().any(|m| m.answer.is_err()))
+            {
+                std::process::exit(1);
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            density,
+            seed,
+        } => {
+            let defaults = generate::defaults(day);
+            let opts = generate::Options {
+                size: size.unwrap_or(defaults.size),
+                density: density.unwrap_or(defaults.density),
+                seed,
+            };
+            match generate::generate(day, opts) {
+                Some(text) => print!("{}", text),
+                None => {
+                    eprintln!("no generator for day {}", day);
+                    std::process::exit(2);
+                }
+            }
+        }
+        Command::Diff {
+            day,
+            runs,
+            size,
+            seed,
+        } => {
+            let days = match day {
+                Some(d) => vec![d],
+                None => bench::days_with_variants(),
+            };
+            quiet_panics();
+            let mut failed = false;
+            for day in days {
+                let opts = generate::Options {
+                    size,
+                    seed,
+                    ..generate::defaults(day)
+                };
+                match differential::search(day, opts, runs) {
+                    None => println!("day {}: variants agree on {} inputs", day, runs),
+                    Some(m) => {
+                        failed = true;
+                        println!("day {}: variants disagree on part {}", m.day, m.part);
+                        for (variant, answer) in &m.answers {
+                            match answer {
+                                Ok(a) => println!("  {:<12}  {}", variant, a),
+                                Err(e) => println!("  {:<12}  {}", variant, e),
+                            }
+                        }
+                        println!("minimal input:\n{}", m.input);
+                    }
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use common::solution::DynSolution;
+
+pub const PARTS: [u8; 2] = [1, 2];
+
+/// Every solution in day order, days with several algorithms list each variant.
+pub static REGISTRY: &[&dyn DynSolution] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::SimpleCompute,
+    &day5::TopologicalSort,
+    &day6::Day6,
+    &day6::Day6BoolArray,
+    &day7::Day7,
+    &day7::Day7Forward,
+    &day8::Day8,
+];
+
+/// Days with at least one solution, in order.
+pub fn days() -> Vec<u8> {
+    let mut days = REGISTRY.iter().map(|s| s.day()).collect::<Vec<_>>();
+    days.dedup();
+    days
+}
+
+/// Solutions of `day`, all variants unless one is named.
+pub fn solutions(day: u8, variant: Option<&str>) -> Vec<&'static dyn DynSolution> {
+    REGISTRY
+        .iter()
+        .copied()
+        .filter(|s| s.day() == day)
+        .filter(|s| variant.is_none_or(|v| s.variant() == v))
+        .collect()
+}
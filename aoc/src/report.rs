@@ -0,0 +1,109 @@
+//! Machine readable results of a run, one record per day, part and variant.
+
+use crate::answers::{self, AnswerStore, Status};
+use common::solution::{Answer, DynSolution, Parsed};
+use serde::Serialize;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub variant: &'static str,
+    /// Hash of the input, see [`answers::input_hash`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+    /// Comparison with the answer store, once [`check`]ed.
+    #[serde(flatten)]
+    pub status: Option<Status>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        #[serde(rename = "parse_ns", serialize_with = "nanos")]
+        parse: Duration,
+        #[serde(rename = "solve_ns", serialize_with = "nanos")]
+        solve: Duration,
+    },
+    /// The input could not be read or parsed, rendered for a terminal.
+    Failed { error: String },
+}
+
+fn nanos<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u64(d.as_nanos() as u64)
+}
+
+/// Parse `buf` once and solve each of `parts` from it. The parse time is
+/// reported with every part, as each of them needed it. A solver that panics
+/// fails its part instead of taking the whole run down.
+pub fn run(solution: &dyn DynSolution, parts: &[u8], buf: &Result<String, String>) -> Vec<Record> {
+    let start = Instant::now();
+    let parsed: Result<Parsed, String> = match buf {
+        Ok(buf) => panic::catch_unwind(AssertUnwindSafe(|| solution.parse(buf)))
+            .map_err(|e| panic_message(e.as_ref()))
+            .and_then(|parsed| parsed.map_err(|e| e.render(buf))),
+        Err(e) => Err(e.clone()),
+    };
+    let parse = start.elapsed();
+    let input = buf.as_ref().ok().map(|buf| answers::input_hash(buf));
+
+    parts
+        .iter()
+        .map(|&part| {
+            let outcome = match &parsed {
+                Ok(parsed) => {
+                    let start = Instant::now();
+                    match panic::catch_unwind(AssertUnwindSafe(|| solution.solve(parsed, part))) {
+                        Ok(Ok(answer)) => Outcome::Solved {
+                            answer,
+                            parse,
+                            solve: start.elapsed(),
+                        },
+                        Ok(Err(e)) => Outcome::Failed {
+                            error: e.to_string(),
+                        },
+                        Err(e) => Outcome::Failed {
+                            error: panic_message(e.as_ref()),
+                        },
+                    }
+                }
+                Err(e) => Outcome::Failed { error: e.clone() },
+            };
+            Record {
+                day: solution.day(),
+                part,
+                variant: solution.variant(),
+                input: input.clone(),
+                outcome,
+                status: None,
+            }
+        })
+        .collect()
+}
+
+/// What a solver panicked with, for reporting next to the answers.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        format!("panicked: {}", s)
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        format!("panicked: {}", s)
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Compare every solved record with the answer recorded for its input.
+pub fn check(records: &mut [Record], store: &AnswerStore) {
+    for r in records.iter_mut() {
+        if let (Outcome::Solved { answer, .. }, Some(input)) = (&r.outcome, &r.input) {
+            r.status = Some(store.check(r.day, r.part, input, answer));
+        }
+    }
+}
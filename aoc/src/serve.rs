@@ -0,0 +1,215 @@
+//! A small local HTTP service around the solvers, for tools that would rather
+//! speak HTTP than link to Rust:
+//!
+//! - `GET /days` lists the days and the variants of each.
+//! - `POST /solve/{day}/{part}` solves the puzzle input sent as the body and
+//!   answers with a [`Record`](crate::report::Record), timings included.
+//!   `?variant=NAME` picks a variant, the day's first one otherwise.
+//!
+//! Every connection is served by its own thread and closed after one
+//! response. Only what these two routes need of HTTP/1.1 is understood.
+
+use crate::registry::{self, PARTS};
+use crate::report::{self, Outcome};
+use serde::Serialize;
+use serde_json::json;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+/// Largest body accepted, well above any real puzzle input.
+pub const MAX_BODY: usize = 16 << 20;
+
+/// How long a client may stay silent before its connection is dropped, so a
+/// stalled one does not hold its thread forever.
+pub const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    /// JSON text.
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        Response {
+            status,
+            body: serde_json::to_string(body).unwrap(),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Response::json(status, &json!({ "error": message.into() }))
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        _ => "Error",
+    }
+}
+
+#[derive(Serialize)]
+struct Day {
+    day: u8,
+    variants: Vec<&'static str>,
+}
+
+/// Answer a request for `target` (path and query) with `body`.
+pub fn route(method: &str, target: &str, body: &[u8]) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments = path.trim_matches('/').split('/').collect::<Vec<_>>();
+    match (method, segments.as_slice()) {
+        ("GET", ["days"]) => {
+            let days = registry::days()
+                .into_iter()
+                .map(|day| Day {
+                    day,
+                    variants: registry::solutions(day, None)
+                        .iter()
+                        .map(|s| s.variant())
+                        .collect(),
+                })
+                .collect::<Vec<_>>();
+            Response::json(200, &days)
+        }
+        ("POST", ["solve", day, part]) => {
+            let variant = query
+                .split('&')
+                .find_map(|kv| kv.strip_prefix("variant="))
+                .filter(|v| !v.is_empty());
+            solve(day, part, variant, body)
+        }
+        (_, ["days"]) | (_, ["solve", _, _]) => {
+            Response::error(405, format!("{} is not allowed on {}", method, path))
+        }
+        _ => Response::error(404, format!("no route for {}", path)),
+    }
+}
+
+fn solve(day: &str, part: &str, variant: Option<&str>, body: &[u8]) -> Response {
+    let Ok(day) = day.parse::<u8>() else {
+        return Response::error(404, format!("no solver for day {}", day));
+    };
+    let Some(solution) = registry::solutions(day, variant).first().copied() else {
+        return Response::error(
+            404,
+            match variant {
+                Some(v) => format!("day {} has no variant {}", day, v),
+                None => format!("no solver for day {}", day),
+            },
+        );
+    };
+    let Some(part) = part.parse::<u8>().ok().filter(|p| PARTS.contains(p)) else {
+        return Response::error(
+            400,
+            format!("part {} does not exist, it must be 1 or 2", part),
+        );
+    };
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Response::error(400, "the input is not UTF-8");
+    };
+
+    let record = report::run(solution, &[part], &Ok(input.to_string())).remove(0);
+    match record.outcome {
+        Outcome::Solved { .. } => Response::json(200, &record),
+        Outcome::Failed { .. } => Response::json(422, &record),
+    }
+}
+
+/// Read one request off `stream` and route it.
+fn handle(stream: &mut TcpStream) -> io::Result<Response> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut request = line.split_whitespace();
+    let (Some(method), Some(target)) = (request.next(), request.next()) else {
+        return Ok(Response::error(400, "malformed request line"));
+    };
+    let (method, target) = (method.to_string(), target.to_string());
+
+    let mut length = None;
+    let mut continue_ = false;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+        let Some((name, value)) = line.split_once(':') else {
+            return Ok(Response::error(400, "malformed header"));
+        };
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            match value.parse::<usize>() {
+                Ok(n) => length = Some(n),
+                Err(_) => return Ok(Response::error(400, "malformed Content-Length")),
+            }
+        } else if name.eq_ignore_ascii_case("expect") {
+            continue_ = value.eq_ignore_ascii_case("100-continue");
+        }
+    }
+
+    let body = match (method.as_str(), length) {
+        ("POST", None) => return Ok(Response::error(411, "the input needs a Content-Length")),
+        (_, Some(n)) if n > MAX_BODY => {
+            return Ok(Response::error(
+                413,
+                format!("inputs are limited to {} bytes", MAX_BODY),
+            ))
+        }
+        (_, Some(n)) => {
+            // curl waits for this before sending a large body
+            if continue_ {
+                stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+            }
+            let mut body = vec![0; n];
+            reader.read_exact(&mut body)?;
+            body
+        }
+        (_, None) => vec![],
+    };
+    Ok(route(&method, &target, &body))
+}
+
+fn respond(stream: &mut TcpStream, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/// Serve requests on `listener` until the process ends. Failed connections
+/// are logged and do not stop the service.
+pub fn serve(listener: TcpListener) {
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("cannot accept a connection: {}", e);
+                continue;
+            }
+        };
+        std::thread::spawn(move || {
+            let result = stream
+                .set_read_timeout(Some(READ_TIMEOUT))
+                .and_then(|()| handle(&mut stream))
+                .and_then(|response| respond(&mut stream, &response));
+            if let Err(e) = result {
+                eprintln!("connection failed: {}", e);
+            }
+        });
+    }
+}
@@ -0,0 +1,168 @@
+//! Re-run a day whenever its input or source files change, and show how the
+//! answers and timings moved since the previous run.
+//!
+//! Each run goes through `cargo run`, so an edited solver is rebuilt before it
+//! runs, and its `--format ndjson` records are read back.
+
+use common::solution::Answer;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{Duration, SystemTime};
+
+/// Root of the workspace, every day crate is a directory of it.
+pub const WORKSPACE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+/// One line of `aoc run --format ndjson`.
+#[derive(Debug, Clone, Deserialize)]
+struct Line {
+    part: u8,
+    variant: String,
+    answer: Option<Answer>,
+    parse_ns: Option<u64>,
+    solve_ns: Option<u64>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timed {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Results of one run, by part and variant.
+pub type Snapshot = BTreeMap<(u8, String), Result<Timed, String>>;
+
+/// The input, the day's crate and the shared crate: everything a run of
+/// `day` depends on that is likely to be edited.
+pub fn watched_files(day: u8, input: &Path) -> Vec<PathBuf> {
+    let root = Path::new(WORKSPACE);
+    let mut files = vec![input.to_path_buf()];
+    for dir in [root.join(format!("day{}", day)), root.join("common")] {
+        files.push(dir.join("Cargo.toml"));
+        collect(&dir.join("src"), &mut files);
+    }
+    files
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect(&path, files);
+        } else {
+            files.push(path);
+        }
+    }
+}
+
+/// Modification times of `files`, compared between polls to spot changes.
+pub fn stamps(files: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    files
+        .iter()
+        .map(|f| std::fs::metadata(f).and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+/// Build and run `aoc run` for `day` on `input`. Compiler errors go straight
+/// to the terminal, and are reported as a failed run.
+pub fn run(day: u8, input: &Path) -> Result<Snapshot, String> {
+    let cargo = std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .current_dir(WORKSPACE)
+        .args(["run", "--quiet", "--release", "--package", "aoc", "--"])
+        .args([
+            "run",
+            "--day",
+            &day.to_string(),
+            "--format",
+            "ndjson",
+            "--input",
+        ])
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("cannot run cargo: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if stdout.trim().is_empty() {
+        return Err(format!("the build or the run failed ({})", output.status));
+    }
+    parse(&stdout)
+}
+
+/// Read back the records of `aoc run --format ndjson`.
+pub fn parse(ndjson: &str) -> Result<Snapshot, String> {
+    ndjson
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| {
+            let line: Line = serde_json::from_str(l).map_err(|e| format!("{}: {}", e, l))?;
+            let result = match (line.answer, line.parse_ns, line.solve_ns) {
+                (Some(answer), Some(parse), Some(solve)) => Ok(Timed {
+                    answer,
+                    parse: Duration::from_nanos(parse),
+                    solve: Duration::from_nanos(solve),
+                }),
+                _ => Err(line.error.unwrap_or_default()),
+            };
+            Ok(((line.part, line.variant), result))
+        })
+        .collect()
+}
+
+/// A table of `current`, with what changed since `previous` next to it.
+pub fn diff(previous: Option<&Snapshot>, current: &Snapshot) -> Vec<String> {
+    let mut lines = vec![format!(
+        "{:>4}  {:<12}  {:>24}  {:>22}  {:>22}",
+        "Part", "Variant", "Answer", "Parse", "Solve"
+    )];
+    for (key @ (part, variant), result) in current {
+        let before = previous.and_then(|p| p.get(key));
+        let line = match (result, before) {
+            (Err(e), _) => format!("{:>4}  {:<12}  error: {}", part, variant, e),
+            (Ok(now), Some(Ok(then))) => format!(
+                "{:>4}  {:<12}  {:>24}  {:>22}  {:>22}",
+                part,
+                variant,
+                if now.answer == then.answer {
+                    now.answer.to_string()
+                } else {
+                    format!("{} -> {}", then.answer, now.answer)
+                },
+                timing(now.parse, Some(then.parse)),
+                timing(now.solve, Some(then.solve))
+            ),
+            (Ok(now), _) => format!(
+                "{:>4}  {:<12}  {:>24}  {:>22}  {:>22}",
+                part,
+                variant,
+                now.answer.to_string(),
+                timing(now.parse, None),
+                timing(now.solve, None)
+            ),
+        };
+        lines.push(line);
+    }
+    if let Some(previous) = previous {
+        for (part, variant) in previous.keys().filter(|k| !current.contains_key(*k)) {
+            lines.push(format!("{:>4}  {:<12}  gone", part, variant));
+        }
+    }
+    lines
+}
+
+fn timing(now: Duration, then: Option<Duration>) -> String {
+    match then {
+        Some(then) if !then.is_zero() => format!(
+            "{:.2?} ({:+.0}%)",
+            now,
+            (now.as_secs_f64() / then.as_secs_f64() - 1.0) * 100.0
+        ),
+        _ => format!("{:.2?}", now),
+    }
+}
@@ -0,0 +1,67 @@
+use aoc::answers::{self, AnswerStore, Status};
+use common::solution::Answer;
+
+#[test]
+fn hash_ignores_trailing_whitespace_only() {
+    assert_eq!(
+        answers::input_hash("1 2\n3 4"),
+        answers::input_hash("1 2\n3 4\n\n")
+    );
+    assert_ne!(
+        answers::input_hash("1 2\n3 4"),
+        answers::input_hash("1 2\n3 5")
+    );
+}
+
+#[test]
+fn fresh_answers_are_checked_against_recorded_ones() {
+    let mut store = AnswerStore::default();
+    let hash = answers::input_hash("3   4\n4   3\n");
+    assert_eq!(store.check(1, 1, &hash, &Answer::Int(0)), Status::Unknown);
+
+    assert_eq!(store.record(1, 1, &hash, Answer::Int(0)), None);
+    assert_eq!(store.check(1, 1, &hash, &Answer::Int(0)), Status::Correct);
+    assert_eq!(
+        store.check(1, 1, &hash, &Answer::Int(1)),
+        Status::Regressed {
+            expected: Answer::Int(0)
+        }
+    );
+    // other parts and inputs are unaffected
+    assert_eq!(store.check(1, 2, &hash, &Answer::Int(0)), Status::Unknown);
+    assert_eq!(store.check(1, 1, "0", &Answer::Int(0)), Status::Unknown);
+}
+
+#[test]
+fn store_round_trips_through_its_file() {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.json", std::process::id()));
+    let mut store = AnswerStore::load(&path).unwrap();
+    store.record(6, 2, "abc", Answer::Int(1703));
+    store.record(3, 1, "def", Answer::Str("mul".to_string()));
+    store.save(&path).unwrap();
+
+    let loaded = AnswerStore::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.get(6, 2, "abc"), Some(&Answer::Int(1703)));
+    assert_eq!(
+        loaded.get(3, 1, "def"),
+        Some(&Answer::Str("mul".to_string()))
+    );
+}
+
+#[test]
+fn record_rejects_a_part_that_does_not_exist() {
+    let path = std::env::temp_dir().join(format!("aoc-record-{}.json", std::process::id()));
+    for answer in [&["--answer", "5"][..], &[]] {
+        let status = std::process::Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .arg("--answers")
+            .arg(&path)
+            .args(["record", "--day", "1", "--part", "3"])
+            .args(answer)
+            .stderr(std::process::Stdio::null())
+            .status()
+            .unwrap();
+        assert_eq!(status.code(), Some(2), "{:?}", answer);
+        assert!(!path.exists());
+    }
+}
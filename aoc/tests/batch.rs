@@ -0,0 +1,44 @@
+use aoc::batch;
+use aoc::registry;
+use aoc::report::Outcome;
+
+#[test]
+fn panics_fail_their_own_input_only() {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("a.txt"), "..#\n.^.\n...\n").unwrap();
+    // no guard on the map: the solvers panic looking for the start
+    std::fs::write(dir.join("b.txt"), "..#\n...\n...\n").unwrap();
+    std::fs::write(dir.join("c.txt"), "#..\n.^.\n...\n").unwrap();
+    // the guard walks in a loop: the solvers give up instead of hanging
+    std::fs::write(dir.join("d.txt"), ".#..\n...#\n#^..\n..#.\n").unwrap();
+    std::fs::write(dir.join("e.txt"), ".#..\n.^.#\n#...\n..#.\n").unwrap();
+
+    let files = batch::inputs(&dir).unwrap();
+    let records = batch::run(&registry::solutions(6, None), &[1, 2], &files, 3);
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    let outcomes = records
+        .iter()
+        .map(|r| {
+            let name = r.file.file_name().unwrap().to_string_lossy().to_string();
+            let outcome = match &r.record.outcome {
+                Outcome::Solved { answer, .. } => answer.to_string(),
+                Outcome::Failed { error } => error.clone(),
+            };
+            (name, r.record.part, outcome)
+        })
+        .collect::<Vec<_>>();
+    let variants = registry::solutions(6, None).len();
+    assert_eq!(outcomes.len(), 5 * variants * 2);
+    for (name, part, outcome) in &outcomes {
+        match (name.as_str(), part) {
+            ("a.txt" | "c.txt", 1) => assert_eq!(outcome, "2", "{}", name),
+            ("a.txt" | "c.txt", _) => assert_eq!(outcome, "0", "{}", name),
+            ("d.txt" | "e.txt", _) => {
+                assert!(outcome.contains("never leaves the map"), "{}", outcome)
+            }
+            _ => assert!(outcome.starts_with("panicked"), "{}", outcome),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use aoc::bench;
+use aoc::generate::{self, Options};
+use std::time::Duration;
+
+#[test]
+fn scaled_day6_maps_keep_one_guard_that_walks_off() {
+    // side by side, the copies of this map trap the guard
+    let map = generate::generate(
+        6,
+        Options {
+            size: 12,
+            density: 0.15,
+            seed: 0,
+        },
+    )
+    .unwrap();
+    for k in [1, 2, 4] {
+        let scaled = bench::scale(6, &map, k);
+        assert_eq!(scaled.matches('^').count(), 1, "scale {}", k);
+        let comparisons = bench::compare(6, &map, &[k], Duration::ZERO).unwrap();
+        for c in &comparisons {
+            assert!(
+                c.measurements.iter().all(|m| m.answer.is_ok()),
+                "scale {} part {}",
+                k,
+                c.part
+            );
+        }
+    }
+}
+
+#[test]
+fn panics_fail_their_measurement_only() {
+    let comparisons = bench::compare(6, ".#..\n.^.#\n#...\n..#.\n", &[1], Duration::ZERO).unwrap();
+    for m in comparisons.iter().flat_map(|c| &c.measurements) {
+        let error = m.answer.as_ref().unwrap_err();
+        assert!(error.contains("never leaves the map"), "{}", error);
+    }
+}
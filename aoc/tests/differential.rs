@@ -0,0 +1,32 @@
+use aoc::bench;
+use aoc::differential::{self, Mismatch};
+use aoc::generate;
+
+#[test]
+fn variants_agree_on_generated_inputs() {
+    for day in bench::days_with_variants() {
+        let opts = generate::Options {
+            size: 12,
+            ..generate::defaults(day)
+        };
+        if let Some(Mismatch {
+            part,
+            input,
+            answers,
+            ..
+        }) = differential::search(day, opts, 200)
+        {
+            panic!(
+                "day {} part {}: variants disagree {:?} on\n{}",
+                day, part, answers, input
+            );
+        }
+    }
+}
+
+#[test]
+fn shrink_keeps_only_what_fails() {
+    let input = "1 2 3\n4 5 6\n7 8 9\n";
+    let shrunk = differential::shrink(input, |t| t.contains('5') && t.contains('9'));
+    assert_eq!(shrunk, "5\n9\n");
+}
@@ -0,0 +1,57 @@
+use aoc::draw;
+use aoc::generate::{self, Options};
+
+fn input(day: u8, seed: u64) -> String {
+    let opts = Options {
+        size: 30,
+        seed,
+        ..generate::defaults(day)
+    };
+    generate::generate(day, opts).unwrap()
+}
+
+#[test]
+fn overlays_cover_what_the_answers_count() {
+    for seed in 0..10 {
+        let grid = day6::parse(&input(6, seed)).unwrap();
+        assert_eq!(day6::guard_path(&grid).len() as u32, day6::part1(&grid));
+        assert_eq!(day6::loop_obstacles(&grid).len() as u32, day6::part2(&grid));
+
+        let grid = day8::parse(&input(8, seed)).unwrap();
+        for (resonant, answer) in [(false, day8::part1(&grid)), (true, day8::part2(&grid))] {
+            let (_, layers) = draw::layers(8, &input(8, seed), 1 + resonant as u8)
+                .unwrap()
+                .unwrap();
+            let mut cells = layers.into_iter().flat_map(|l| l.cells).collect::<Vec<_>>();
+            cells.sort_unstable();
+            cells.dedup();
+            assert_eq!(cells.len(), answer, "seed {} resonant {}", seed, resonant);
+        }
+
+        let grid = day4::parse(&input(4, seed)).unwrap();
+        assert_eq!(day4::xmas_cells(&grid).is_empty(), day4::part1(&grid) == 0);
+        assert_eq!(day4::x_mas_cells(&grid).is_empty(), day4::part2(&grid) == 0);
+    }
+}
+
+#[test]
+fn only_grid_days_draw() {
+    for day in 1..=8 {
+        assert_eq!(
+            draw::layers(day, "^.\n..\n", 1).is_some(),
+            draw::DAYS.contains(&day)
+        );
+    }
+}
+
+#[test]
+fn day6_maps_the_guard_cannot_walk_are_errors() {
+    for part in [1, 2] {
+        let err = draw::layers(6, "..#\n...\n", part).unwrap().unwrap_err();
+        assert!(matches!(err, draw::DrawError::NoGuard), "{:?}", err);
+    }
+    let looping = ".#..\n...#\n#^..\n..#.\n";
+    assert!(draw::layers(6, looping, 1).unwrap().is_ok());
+    let err = draw::layers(6, looping, 2).unwrap().unwrap_err();
+    assert_eq!(err.to_string(), "the guard never leaves the map");
+}
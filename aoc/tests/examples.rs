@@ -0,0 +1,98 @@
+use aoc::examples::{self, Example};
+use aoc::registry;
+
+/// Run every annotated example of `day`'s writeup through all its solvers.
+fn check_day(day: u8) {
+    let path = examples::writeup(day);
+    let markdown = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+    let examples = examples::extract(&markdown).unwrap();
+    assert!(
+        !examples.is_empty(),
+        "{} has no annotated example",
+        path.display()
+    );
+
+    let mut failures = vec![];
+    for Example {
+        line,
+        input,
+        answers,
+        variant,
+    } in examples.iter()
+    {
+        for (part, expected) in answers {
+            let solutions = registry::solutions(day, variant.as_deref());
+            assert!(!solutions.is_empty(), "line {}: no solution to check", line);
+
+            for s in solutions {
+                match s.parse(input).map(|parsed| s.solve(&parsed, *part)) {
+                    Ok(Ok(answer)) if answer.to_string() == *expected => {}
+                    Ok(Ok(answer)) => failures.push(format!(
+                        "line {}: part {} ({}) answered {}, the writeup says {}",
+                        line,
+                        part,
+                        s.variant(),
+                        answer,
+                        expected
+                    )),
+                    Ok(Err(e)) => failures.push(format!(
+                        "line {}: part {} ({}) has no answer: {}",
+                        line,
+                        part,
+                        s.variant(),
+                        e
+                    )),
+                    Err(e) => failures.push(format!(
+                        "line {}: part {} ({}) failed to parse the example:\n{}",
+                        line,
+                        part,
+                        s.variant(),
+                        e.render(input)
+                    )),
+                }
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "{} disagrees with the code:\n{}",
+        path.display(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn day1() {
+    check_day(1);
+}
+
+#[test]
+fn day2() {
+    check_day(2);
+}
+
+#[test]
+fn day3() {
+    check_day(3);
+}
+
+#[test]
+fn day4() {
+    check_day(4);
+}
+
+#[test]
+fn day5() {
+    check_day(5);
+}
+
+#[test]
+fn day6() {
+    check_day(6);
+}
+
+#[test]
+fn day7() {
+    check_day(7);
+}
@@ -0,0 +1,62 @@
+use aoc::generate::{self, Options};
+use aoc::registry;
+
+#[test]
+fn same_seed_same_input() {
+    for day in registry::days() {
+        let opts = Options {
+            seed: 42,
+            ..generate::defaults(day)
+        };
+        assert_eq!(
+            generate::generate(day, opts),
+            generate::generate(day, opts),
+            "day {}",
+            day
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_parse() {
+    for day in registry::days() {
+        for seed in 0..5 {
+            for density in [0.0, 0.3, 1.0] {
+                let opts = Options {
+                    size: 20,
+                    density,
+                    seed,
+                };
+                let text = generate::generate(day, opts).unwrap();
+                for s in registry::solutions(day, None) {
+                    if let Err(e) = s.parse(&text) {
+                        panic!(
+                            "day {} seed {} density {}:\n{}",
+                            day,
+                            seed,
+                            density,
+                            e.render(&text)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn day7_lines_are_as_long_as_in_puzzle_inputs() {
+    let opts = Options {
+        size: 100,
+        ..generate::defaults(7)
+    };
+    let text = generate::generate(7, opts).unwrap();
+    let input = day7::parse(&text).unwrap();
+    let numbers = || input.equations.iter().flat_map(|e| &e.numbers);
+    let longest = input.equations.iter().map(|e| e.numbers.len()).max();
+    assert_eq!(longest, Some(12));
+    assert!(numbers().all(|n| (1..1000).contains(n)));
+    assert!(numbers().any(|&n| n >= 100));
+    // most operators overflow on such lines, the variants must still agree
+    assert_eq!(day7::part2(&input), day7::part2_forward(&input));
+}
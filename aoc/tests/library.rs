@@ -0,0 +1,285 @@
+//! The helpers each day exposes besides its parts, on the puzzle examples.
+
+use common::geometry::{Direction, Point};
+use common::grid::Grid;
+use std::collections::{HashMap, HashSet};
+
+#[test]
+fn day2_reports() {
+    assert!(day2::report_is_valid(&[7, 6, 4, 2, 1]));
+    assert!(!day2::report_is_valid(&[1, 2, 7, 8, 9]));
+    assert!(!day2::report_is_valid(&[1, 3, 2, 4, 5]));
+    assert!(day2::report_is_tolerable(&[1, 3, 2, 4, 5]));
+    assert!(!day2::report_is_tolerable(&[9, 7, 6, 2, 1]));
+}
+
+#[test]
+fn day4_patterns() {
+    let map = Grid::parse("XMAS\n.A..\nM.S.\nS...\n").unwrap();
+    assert_eq!(day4::find_pattern((0, 0), &map, &['M', 'A', 'S']), 1);
+    assert_eq!(day4::find_pattern((0, 1), &map, &['M', 'A', 'S']), 0);
+
+    let map = Grid::parse("M.S\n.A.\nM.S\n").unwrap();
+    assert_eq!(day4::find_pattern2((1, 1), &map), 1);
+    assert_eq!(day4::find_pattern2((0, 0), &map), 0);
+}
+
+#[test]
+fn day5_topological_sort() {
+    let rules = [(97, 75), (75, 47), (97, 47), (47, 29), (75, 29)];
+    let mut after: HashMap<i32, HashSet<i32>> = HashMap::new();
+    let mut before: HashMap<i32, HashSet<i32>> = HashMap::new();
+    for (x, y) in rules {
+        after.entry(x).or_default().insert(y);
+        before.entry(y).or_default().insert(x);
+    }
+    assert_eq!(
+        day5::topological_sort(&after, &before, &[29, 47, 97, 75]),
+        [97, 75, 47, 29]
+    );
+}
+
+#[test]
+fn day6_walks() {
+    let map = Grid::parse("#...\n...#\n^...\n").unwrap();
+    assert_eq!(day6::find_start(&map), (2, 0));
+
+    let mut m = map.clone();
+    m[(2, 0)] = '.';
+    let start = (2, 0, Direction::Up);
+    assert_eq!(day6::out_of_grid(&start, &m), 5);
+    assert_eq!(day6::out_of_grid2(&start, &m), Some(5));
+
+    let trap = Grid::parse(".#..\n...#\n#...\n..#.\n").unwrap();
+    assert_eq!(day6::out_of_grid2(&(1, 1, Direction::Up), &trap), None);
+}
+
+#[test]
+fn day7_equations() {
+    let eqt = day7::Equation {
+        value: 7290,
+        numbers: vec![6, 8, 6, 15],
+    };
+    assert!(!eqt.is_valid(6, &[8, 6, 15]));
+    assert!(eqt.is_valid2(6, &[8, 6, 15]));
+    assert!(eqt.is_valid2_revert(7290, &[15, 6, 8, 6]));
+
+    let eqt = day7::Equation {
+        value: 83,
+        numbers: vec![17, 5],
+    };
+    assert!(!eqt.is_valid2(17, &[5]));
+    assert!(!eqt.is_valid2_revert(83, &[5, 17]));
+
+    // every way of combining these overflows an i64 or misses 1
+    let input = day7::parse("1: 123 456 789 123 456 789 12\n").unwrap();
+    assert_eq!(day7::part1(&input), 0);
+    assert_eq!(day7::part2(&input), 0);
+    assert_eq!(day7::part2_forward(&input), 0);
+    let input = day7::parse("123456789123456789: 123 456 789 123 456 789\n").unwrap();
+    assert_eq!(day7::part2(&input), 123456789123456789);
+    assert_eq!(day7::part2_forward(&input), 123456789123456789);
+
+    // -5 * 3 + 5, where searching forwards cannot stop past the value
+    let input = day7::parse("-10: -5 3 5\n").unwrap();
+    assert_eq!(day7::part1(&input), -10);
+    assert_eq!(day7::part2_forward(&input), -10);
+}
+
+#[test]
+fn day8_antennas() {
+    let grid = Grid::parse("a..\n..A\n.a.\n").unwrap();
+    let antennas = day8::antennas(&grid);
+    assert_eq!(antennas.len(), 2);
+    assert_eq!(antennas[&'A'], [Point::from_index((1, 2)).unwrap()]);
+    assert_eq!(antennas[&'a'].len(), 2);
+}
+
+#[test]
+fn day1_totals_are_wide_and_checked() {
+    assert_eq!(day1::total_distance(&[3u8, 4, 2], &[4, 3, 5]), Ok(3));
+    assert_eq!(day1::total_distance(&[-3i32, 4], &[5, -1]), Ok(3));
+    assert_eq!(day1::similarity_score(&[-2i64, 3], &[-2, -2, 3]), Ok(-1));
+
+    // u32 distances and products that wrap in 32 bits
+    assert_eq!(
+        day1::total_distance(&[0u32, 0], &[u32::MAX, u32::MAX]),
+        Ok(2 * u32::MAX as i128)
+    );
+    assert_eq!(
+        day1::similarity_score(&[u32::MAX], &[u32::MAX, u32::MAX]),
+        Ok(2 * u32::MAX as i128)
+    );
+    assert_eq!(
+        day1::total_distance(&[i64::MIN], &[i64::MAX]),
+        Ok(u64::MAX as i128)
+    );
+
+    assert_eq!(
+        day1::total_distance(&[i128::MIN], &[i128::MAX]),
+        Err(day1::Overflow)
+    );
+    assert_eq!(
+        day1::similarity_score(&[i128::MAX], &[i128::MAX, i128::MAX]),
+        Err(day1::Overflow)
+    );
+}
+
+#[test]
+fn day1_pairings_explain_the_total_distance() {
+    let input = day1::parse("3   4\n4   3\n\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    let pairs = day1::pairings(&input);
+    assert_eq!(
+        pairs.iter().map(|p| p.distance).sum::<i128>(),
+        day1::part1(&input).unwrap()
+    );
+    let distances = pairs.iter().map(|p| p.distance).collect::<Vec<_>>();
+    assert_eq!(distances, [5, 2, 2, 1, 1, 0]);
+    assert_eq!(
+        pairs[0],
+        day1::Pairing {
+            left: 4,
+            right: 9,
+            left_line: Some(2),
+            right_line: Some(6),
+            distance: 5,
+        }
+    );
+    // the 3s on the left are paired in input order: the one of line 1 with
+    // the smallest right ID, the one of line 7 with the largest
+    assert_eq!(
+        (pairs[5].left, pairs[5].left_line, pairs[5].right),
+        (3, Some(1), 3)
+    );
+    assert_eq!(
+        (pairs[2].left, pairs[2].left_line, pairs[2].right),
+        (3, Some(7), 5)
+    );
+}
+
+#[test]
+fn day1_compares_every_pair_of_columns() {
+    let input = day1::parse("3 4 1\n4 3 2\n2 5 3\n1 3 4\n3 9 5\n3 3 3\n").unwrap();
+    assert_eq!(input.columns.len(), 3);
+
+    let distances = day1::matrix(&input.columns, day1::total_distance).unwrap();
+    assert_eq!(distances, [[0, 11, 2], [11, 0, 9], [2, 9, 0]]);
+    let similarities = day1::matrix(&input.columns, day1::similarity_score).unwrap();
+    assert_eq!(similarities[0][1], day1::part2(&input).unwrap());
+    assert_eq!(similarities[0][1], 31);
+
+    let e = day1::parse("1 2 3\n4 5\n").err().unwrap();
+    assert_eq!((e.line, e.expected.as_str()), (2, "3 numbers as on line 1"));
+    let e = day1::parse("1 2\n4 5 6\n").err().unwrap();
+    assert_eq!((e.line, e.expected.as_str()), (1, "3 numbers as on line 2"));
+    assert_eq!(
+        day1::parse("7\n").err().unwrap().expected,
+        "a second number"
+    );
+}
+
+#[test]
+fn day1_unequal_columns_follow_the_policy() {
+    use day1::Unequal;
+    let text = "3 4\n4\n2 5\n1 3\n";
+
+    let e = day1::parse(text).err().unwrap();
+    assert_eq!((e.line, e.expected.as_str()), (2, "2 numbers as on line 1"));
+    assert_eq!(day1::parse_with(text, Unequal::default()).err(), Some(e));
+
+    let input = day1::parse_with(text, Unequal::Truncate).unwrap();
+    assert_eq!(input.columns, [vec![3, 4, 2], vec![4, 5, 3]]);
+    assert_eq!(input.lines, [vec![1, 2, 3], vec![1, 3, 4]]);
+    assert_eq!(input.read, [4, 3]);
+    assert_eq!(day1::part1(&input), Ok(3));
+
+    let input = day1::parse_with(text, Unequal::Pad(-1)).unwrap();
+    assert_eq!(input.columns[1], [4, 5, 3, -1]);
+    assert_eq!(input.unequal.to_string(), "pad with -1");
+    let padded = day1::pairings(&input);
+    assert_eq!(padded[0].right_line, None);
+    assert_eq!((padded[0].left, padded[0].distance), (1, 2));
+    assert_eq!(day1::part1(&input), Ok(2 + 1 + 1 + 1));
+    assert_eq!(
+        day1::unequal_note(&input),
+        "unequal columns: pad with -1, the columns had 4, 3 numbers"
+    );
+
+    // what `aoc run --unequal` solves with
+    let run = day1::Day1With(Unequal::Truncate);
+    let records = aoc::report::run(&run, &[1], &Ok(text.to_string()));
+    assert_eq!(serde_json::to_value(&records).unwrap()[0]["answer"], 3);
+}
+
+#[test]
+fn day1_metrics_score_the_sorted_pairs() {
+    use day1::metrics::{self, Metric};
+    let pairs = metrics::sorted_pairs(&[3, 4, 2, 1, 3, 3], &[4, 3, 5, 3, 9, 3]);
+    assert_eq!(metrics::L1.score(&pairs), 11.0);
+    assert_eq!(
+        metrics::L2.score(&pairs),
+        4.0 + 1.0 + 0.0 + 1.0 + 4.0 + 25.0
+    );
+    assert_eq!(metrics::LInf.score(&pairs), 5.0);
+    assert_eq!(metrics::Wasserstein.score(&pairs), 11.0 / 6.0 / 8.0);
+    assert_eq!(metrics::Wasserstein.score(&[(7, 7)]), 0.0);
+    assert_eq!(metrics::METRICS.len(), 4);
+}
+
+#[test]
+fn day1_optimal_assignment_beats_sorting_under_a_concave_cost() {
+    use day1::metrics;
+    let sqrt = metrics::power_cost(0.5);
+    // sorted: 0-1 and 1-2 cost 2, crossing: 0-2 and 1-1 cost sqrt 2
+    let optimal = metrics::optimal_assignment(&[0, 1], &[1, 2], &sqrt);
+    assert_eq!(optimal, [(0, 2), (1, 1)]);
+
+    // against every permutation of small random lists
+    let mut seed = 7u64;
+    let mut next = || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as i64 % 50
+    };
+    for n in 1..=6 {
+        let firsts = (0..n).map(|_| next()).collect::<Vec<_>>();
+        let seconds = (0..n).map(|_| next()).collect::<Vec<_>>();
+        let best = permutations(n)
+            .iter()
+            .map(|p| (0..n).map(|i| sqrt(firsts[i], seconds[p[i]])).sum::<f64>())
+            .fold(f64::INFINITY, f64::min);
+        let optimal = metrics::optimal_assignment(&firsts, &seconds, &sqrt);
+        assert!((metrics::cost_of(&optimal, &sqrt) - best).abs() < 1e-9);
+        let sorted = metrics::sorted_pairs(&firsts, &seconds);
+        assert!(metrics::cost_of(&sorted, &sqrt) >= best - 1e-9);
+
+        // for a convex cost sorting is optimal
+        let linear = metrics::power_cost(1.0);
+        let optimal = metrics::optimal_assignment(&firsts, &seconds, &linear);
+        assert_eq!(
+            metrics::cost_of(&optimal, &linear),
+            metrics::cost_of(&sorted, &linear)
+        );
+    }
+
+    // the shorter list is matched in full, whichever side it is on
+    let optimal = metrics::optimal_assignment(&[1, 10, 20], &[11], &sqrt);
+    assert_eq!(optimal, [(10, 11)]);
+}
+
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    permutations(n - 1)
+        .into_iter()
+        .flat_map(|p| {
+            (0..n).map(move |i| {
+                let mut q = p.clone();
+                q.insert(i, n - 1);
+                q
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use aoc::registry;
+use aoc::report;
+use common::parse::ParseError;
+use common::solution::{Answer, Solution, SolveError};
+
+/// Part 1 has no answer, part 2 answers 0.
+struct NoAnswer;
+
+impl Solution for NoAnswer {
+    const DAY: u8 = 1;
+    const DEFAULT_INPUT: &'static str = "";
+    type Input = ();
+
+    fn parse(_: &str) -> Result<(), ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &()) -> Result<Answer, SolveError> {
+        Err(SolveError("the total overflows".to_string()))
+    }
+
+    fn part2(_: &()) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
+}
+
+#[test]
+fn records_serialize_answer_and_phase_timings() {
+    let day1 = registry::solutions(1, None)[0];
+    let records = report::run(day1, &[1, 2], &Ok("3   4\n4   3\n".to_string()));
+    let json = serde_json::to_value(&records).unwrap();
+
+    assert_eq!(json[0]["day"], 1);
+    assert_eq!(json[0]["part"], 1);
+    assert_eq!(json[0]["variant"], "default");
+    assert_eq!(json[0]["answer"], 0);
+    assert_eq!(json[1]["answer"], 7);
+    assert!(json[0]["parse_ns"].is_u64());
+    assert!(json[0]["solve_ns"].is_u64());
+    assert_eq!(json[0]["parse_ns"], json[1]["parse_ns"]);
+}
+
+#[test]
+fn records_carry_errors_instead_of_answers() {
+    let day1 = registry::solutions(1, None)[0];
+    let records = report::run(day1, &[1], &Ok("3   x\n".to_string()));
+    let json = serde_json::to_value(&records).unwrap();
+
+    assert!(json[0].get("answer").is_none());
+    assert!(json[0]["error"].as_str().unwrap().contains("line 1"));
+}
+
+#[test]
+fn parts_without_an_answer_fail_without_a_panic() {
+    let records = report::run(&NoAnswer, &[1, 2], &Ok(String::new()));
+    let json = serde_json::to_value(&records).unwrap();
+
+    assert_eq!(json[0]["error"], "the total overflows");
+    assert_eq!(json[1]["answer"], 0);
+}
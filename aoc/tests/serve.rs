@@ -0,0 +1,128 @@
+use aoc::serve;
+use serde_json::Value;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::OnceLock;
+
+const DAY1: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+/// One server on an ephemeral port, shared by the tests.
+fn server() -> SocketAddr {
+    static ADDR: OnceLock<SocketAddr> = OnceLock::new();
+    *ADDR.get_or_init(|| {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        std::thread::spawn(move || serve::serve(listener));
+        addr
+    })
+}
+
+/// Send a raw request and return the status and JSON body of the response.
+fn send(request: &[u8]) -> (u16, Value) {
+    let mut stream = TcpStream::connect(server()).unwrap();
+    stream.write_all(request).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    assert!(head.contains("Content-Type: application/json"), "{}", head);
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+fn post(target: &str, body: &str) -> (u16, Value) {
+    send(
+        format!(
+            "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+            target,
+            body.len(),
+            body
+        )
+        .as_bytes(),
+    )
+}
+
+#[test]
+fn days_lists_every_day_and_variant() {
+    let (status, days) = send(b"GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n");
+    assert_eq!(status, 200);
+    let days = days.as_array().unwrap();
+    assert_eq!(days.len(), aoc::registry::days().len());
+    assert_eq!(days[0]["day"], 1);
+    assert_eq!(
+        days[4]["variants"],
+        serde_json::json!(["simple", "topological"])
+    );
+}
+
+#[test]
+fn solve_answers_with_timings() {
+    let (status, record) = post("/solve/1/2", DAY1);
+    assert_eq!(status, 200);
+    assert_eq!(record["day"], 1);
+    assert_eq!(record["part"], 2);
+    assert_eq!(record["answer"], 31);
+    assert!(record["parse_ns"].is_u64());
+    assert!(record["solve_ns"].is_u64());
+
+    let (status, record) = post("/solve/5/1?variant=topological", "1|2\n\n1,2,3\n");
+    assert_eq!((status, &record["variant"]), (200, &"topological".into()));
+}
+
+#[test]
+fn expect_continue_is_honoured() {
+    let mut stream = TcpStream::connect(server()).unwrap();
+    write!(
+        stream,
+        "POST /solve/1/1 HTTP/1.1\r\nContent-Length: {}\r\nExpect: 100-continue\r\n\r\n",
+        DAY1.len()
+    )
+    .unwrap();
+    let mut interim = [0; 25];
+    stream.read_exact(&mut interim).unwrap();
+    assert_eq!(&interim, b"HTTP/1.1 100 Continue\r\n\r\n");
+    stream.write_all(DAY1.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    assert!(response.starts_with("HTTP/1.1 200 OK"), "{}", response);
+    assert!(response.contains(r#""answer":11"#), "{}", response);
+}
+
+#[test]
+fn errors_have_a_status_and_a_message() {
+    let (status, body) = post("/solve/1/1", "3 x\n");
+    assert_eq!(status, 422);
+    assert!(
+        body["error"].as_str().unwrap().contains("line 1"),
+        "{}",
+        body
+    );
+
+    let (status, body) = post("/solve/26/1", "");
+    assert_eq!(
+        (status, body["error"].as_str()),
+        (404, Some("no solver for day 26"))
+    );
+    assert_eq!(post("/solve/5/1?variant=bogo", "").0, 404);
+    assert_eq!(post("/solve/1/3", DAY1).0, 400);
+    assert_eq!(post("/nowhere", "").0, 404);
+    assert_eq!(send(b"GET /solve/1/1 HTTP/1.1\r\n\r\n").0, 405);
+    assert_eq!(send(b"POST /solve/1/1 HTTP/1.1\r\n\r\n").0, 411);
+
+    // a panicking solver fails the request, not the service
+    let (status, body) = post("/solve/6/1", "..\n..\n");
+    assert_eq!(status, 422);
+    assert!(body["error"].as_str().unwrap().starts_with("panicked"));
+    // and so does a guard walking in a loop, instead of spinning forever
+    let (status, body) = post("/solve/6/1", ".#..\n...#\n#^..\n..#.\n");
+    assert_eq!(status, 422);
+    assert!(
+        body["error"]
+            .as_str()
+            .unwrap()
+            .contains("never leaves the map"),
+        "{}",
+        body
+    );
+    assert_eq!(post("/solve/1/1", DAY1).1["answer"], 11);
+}
@@ -0,0 +1,21 @@
+use common::trace;
+
+fn day7_answers(input: &day7::Input) -> (i64, i64, i64) {
+    (
+        day7::part1(input),
+        day7::part2(input),
+        day7::part2_forward(input),
+    )
+}
+
+#[test]
+fn tracing_does_not_change_the_answers() {
+    // the first equation overflows an i64 whichever operators are tried
+    let input = day7::parse("1: 123 456 789 123 456 789 12\n190: 10 19\n").unwrap();
+    let answers = day7_answers(&input);
+    assert_eq!(answers, (190, 190, 190));
+
+    trace::set_level(2);
+    assert!(trace::enabled(2));
+    assert_eq!(day7_answers(&input), answers);
+}
@@ -0,0 +1,40 @@
+use aoc::watch;
+
+const BEFORE: &str = r#"{"day":7,"part":1,"variant":"reverse","answer":3749,"parse_ns":1000,"solve_ns":2000}
+{"day":7,"part":2,"variant":"reverse","answer":11387,"parse_ns":1000,"solve_ns":4000}
+"#;
+
+#[test]
+fn parse_reads_answers_timings_and_errors() {
+    let snapshot = watch::parse(
+        r#"{"day":1,"part":1,"variant":"default","answer":11,"parse_ns":5,"solve_ns":7}
+{"day":1,"part":2,"variant":"default","error":"line 1, column 3: expected an integer, found `x`"}
+"#,
+    )
+    .unwrap();
+    let part1 = snapshot[&(1, "default".to_string())].as_ref().unwrap();
+    assert_eq!(part1.answer.to_string(), "11");
+    assert_eq!(part1.solve.as_nanos(), 7);
+    assert!(snapshot[&(2, "default".to_string())]
+        .as_ref()
+        .unwrap_err()
+        .contains("expected an integer"));
+}
+
+#[test]
+fn diff_shows_changed_answers_and_relative_timings() {
+    let before = watch::parse(BEFORE).unwrap();
+    let after = watch::parse(&BEFORE.replace("11387", "11392").replace("4000", "2000")).unwrap();
+    let lines = watch::diff(Some(&before), &after);
+
+    assert_eq!(lines.len(), 3);
+    assert!(!lines[1].contains("->"), "{}", lines[1]);
+    assert!(lines[2].contains("11387 -> 11392"), "{}", lines[2]);
+    assert!(lines[2].contains("(-50%)"), "{}", lines[2]);
+}
+
+#[test]
+fn first_run_has_nothing_to_compare() {
+    let lines = watch::diff(None, &watch::parse(BEFORE).unwrap());
+    assert!(lines.iter().all(|l| !l.contains("->") && !l.contains('%')));
+}
@@ -0,0 +1,232 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed position or offset on a grid, `row` growing downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ZERO: Point = Point { row: 0, col: 0 };
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    /// Convert a grid index, `None` if it does not fit in an `isize`.
+    pub fn from_index((row, col): (usize, usize)) -> Option<Self> {
+        Some(Point {
+            row: isize::try_from(row).ok()?,
+            col: isize::try_from(col).ok()?,
+        })
+    }
+
+    /// Convert back to a grid index, `None` if either coordinate is negative.
+    /// Whether the index is inside a given grid is left to the grid.
+    pub fn to_index(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+
+    /// Divide both coordinates by their greatest common divisor, giving the
+    /// smallest step on the grid along the same line. `ZERO` stays `ZERO`.
+    pub fn reduce(self) -> Self {
+        let g = gcd(self.row.unsigned_abs(), self.col.unsigned_abs()) as isize;
+        if g == 0 {
+            self
+        } else {
+            Point::new(self.row / g, self.col / g)
+        }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((row, col): (isize, isize)) -> Self {
+        Point { row, col }
+    }
+}
+
+impl From<Point> for (isize, isize) {
+    fn from(p: Point) -> Self {
+        (p.row, p.col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, k: isize) -> Point {
+        Point::new(self.row * k, self.col * k)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+/// One of the 4 orthogonal directions, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Position in [`Direction::ALL`], handy to index per-direction arrays.
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self.index() + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self.index() + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction::ALL[(self.index() + 2) % 4]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(-1, 0),
+            Direction::Right => Point::new(0, 1),
+            Direction::Down => Point::new(1, 0),
+            Direction::Left => Point::new(0, -1),
+        }
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(d: Direction) -> Self {
+        d.delta()
+    }
+}
+
+/// One of the 8 compass directions, in clockwise order starting from `N` (up).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    pub fn turn_right(self) -> Self {
+        Dir8::ALL[(self.index() + 2) % 8]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Dir8::ALL[(self.index() + 6) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Dir8::ALL[(self.index() + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Dir8::N => Point::new(-1, 0),
+            Dir8::NE => Point::new(-1, 1),
+            Dir8::E => Point::new(0, 1),
+            Dir8::SE => Point::new(1, 1),
+            Dir8::S => Point::new(1, 0),
+            Dir8::SW => Point::new(1, -1),
+            Dir8::W => Point::new(0, -1),
+            Dir8::NW => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir8> for Point {
+    fn from(d: Dir8) -> Self {
+        d.delta()
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(d: Direction) -> Self {
+        Dir8::ALL[d.index() * 2]
+    }
+}
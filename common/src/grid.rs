@@ -0,0 +1,264 @@
+use crate::geometry::{Dir8, Direction, Point};
+use crate::parse::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    Empty,
+    /// `row` (0-based) does not have the same length as the first row.
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::Ragged {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} cells, expected {}",
+                row + 1,
+                found,
+                expected
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GridError {}
+
+/// A rectangular grid stored row by row, indexed by `(row, col)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl Grid<char> {
+    /// Parse one row per line, one cell per char.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        Grid::parse_with(text, |c| c)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parse one row per line, mapping every char of a line to a cell.
+    /// Trailing blank lines are ignored.
+    pub fn parse_with(text: &str, f: impl Fn(char) -> T) -> Result<Self, ParseError> {
+        let mut lines = text
+            .lines()
+            .map(|line| line.trim_end_matches('\r'))
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+        let Some(first) = lines.first() else {
+            return Err(ParseError::end_of_line(1, "", "a grid"));
+        };
+
+        let width = first.chars().count();
+        for (i, line) in lines.iter().enumerate() {
+            if let Some((extra, _)) = line.char_indices().nth(width) {
+                return Err(ParseError::at(i + 1, line, &line[extra..], "end of row"));
+            }
+            if line.chars().count() < width {
+                return Err(ParseError::end_of_line(
+                    i + 1,
+                    line,
+                    format!("a row of {} cells", width),
+                ));
+            }
+        }
+
+        Ok(Grid {
+            cells: lines.iter().flat_map(|l| l.chars()).map(f).collect(),
+            width,
+            height: lines.len(),
+        })
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let width = rows.first().map(|r| r.len()).unwrap_or(0);
+        if width == 0 {
+            return Err(GridError::Empty);
+        }
+        if let Some((row, r)) = rows.iter().enumerate().find(|(_, r)| r.len() != width) {
+            return Err(GridError::Ragged {
+                row,
+                expected: width,
+                found: r.len(),
+            });
+        }
+        let height = rows.len();
+        Ok(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `p` lies inside the grid.
+    pub fn contains(&self, p: Point) -> bool {
+        self.index_of(p).is_some()
+    }
+
+    /// Checked conversion of `p` to a `(row, col)` index of this grid.
+    pub fn index_of(&self, p: Point) -> Option<(usize, usize)> {
+        p.to_index()
+            .filter(|&(row, col)| row < self.height && col < self.width)
+    }
+
+    pub fn get_at(&self, p: Point) -> Option<&T> {
+        self.index_of(p).map(|pos| &self[pos])
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            Some(&self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.height && col < self.width {
+            Some(&mut self.cells[row * self.width + col])
+        } else {
+            None
+        }
+    }
+
+    /// The position one `delta` away from `pos`, if it is still inside the grid.
+    pub fn step(&self, pos: (usize, usize), delta: impl Into<Point>) -> Option<(usize, usize)> {
+        self.index_of(Point::from_index(pos)? + delta.into())
+    }
+
+    /// In-grid orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .iter()
+            .filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// In-grid orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /// Walk from `start` (excluded) in steps of `delta` until leaving the grid.
+    pub fn ray(&self, start: (usize, usize), delta: impl Into<Point>) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            pos: Some(start),
+            delta: delta.into(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All cells with their `(row, col)`, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, c)| ((i / width, i % width), c))
+    }
+
+    /// Position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.positions_of(value).next()
+    }
+
+    /// Positions of every cell equal to `value`, row by row.
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.indexed_iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(col < self.width, "column {} out of bounds", col);
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(col < self.width, "column {} out of bounds", col);
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Iterator returned by [`Grid::ray`].
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    pos: Option<(usize, usize)>,
+    delta: Point,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos = self.grid.step(self.pos?, self.delta);
+        let pos = self.pos?;
+        Some((pos, &self.grid[pos]))
+    }
+}
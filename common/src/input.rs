@@ -0,0 +1,155 @@
+use std::fmt;
+use std::io::{IsTerminal, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// A path passed explicitly on the command line.
+    Explicit(PathBuf),
+    /// A path taken from the `AOC_DAY{N}_INPUT` environment variable.
+    Env(PathBuf),
+    /// The day's own default file inside its crate.
+    Default(PathBuf),
+    Stdin,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Explicit(p) => write!(f, "{}", p.display()),
+            Source::Env(p) => write!(f, "{} (from environment)", p.display()),
+            Source::Default(p) => write!(f, "{} (default)", p.display()),
+            Source::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+pub struct Input {
+    pub text: String,
+    pub source: Source,
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// A path was given (explicitly or through the environment) but could not be read.
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    /// Every location was tried and none of them had an input.
+    NotFound { day: u8, tried: Vec<String> },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io { path, source } => {
+                write!(f, "cannot read {}: {}", path.display(), source)
+            }
+            InputError::NotFound { day, tried } => {
+                write!(f, "no input found for day {}, tried:", day)?;
+                for t in tried {
+                    write!(f, "\n  - {}", t)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } => Some(source),
+            InputError::NotFound { .. } => None,
+        }
+    }
+}
+
+/// Name of the environment variable that overrides the input of `day`.
+pub fn env_var(day: u8) -> String {
+    format!("AOC_DAY{}_INPUT", day)
+}
+
+/// Resolve the puzzle input of `day`, trying in order:
+/// 1. the `explicit` path,
+/// 2. the `AOC_DAY{N}_INPUT` environment variable,
+/// 3. the day's `default` file,
+/// 4. stdin, when it is piped rather than a terminal.
+pub fn resolve(day: u8, explicit: Option<&Path>, default: &str) -> Result<Input, InputError> {
+    if let Some(path) = explicit {
+        return read(Source::Explicit(path.to_path_buf()));
+    }
+
+    let mut tried = vec!["command line argument (not given)".to_string()];
+
+    let var = env_var(day);
+    match std::env::var_os(&var) {
+        Some(path) if !path.is_empty() => return read(Source::Env(PathBuf::from(path))),
+        _ => tried.push(format!("${} (not set)", var)),
+    }
+
+    let default = PathBuf::from(default);
+    if default.is_file() {
+        return read(Source::Default(default));
+    }
+    tried.push(format!("{} (no such file)", default.display()));
+
+    let stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        tried.push("stdin (is a terminal)".to_string());
+    } else {
+        let mut text = String::new();
+        stdin
+            .lock()
+            .read_to_string(&mut text)
+            .map_err(|source| InputError::Io {
+                path: PathBuf::from("<stdin>"),
+                source,
+            })?;
+        if !text.is_empty() {
+            return Ok(Input {
+                text,
+                source: Source::Stdin,
+            });
+        }
+        tried.push("stdin (empty)".to_string());
+    }
+
+    Err(InputError::NotFound { day, tried })
+}
+
+fn read(source: Source) -> Result<Input, InputError> {
+    let path = match &source {
+        Source::Explicit(p) | Source::Env(p) | Source::Default(p) => p,
+        Source::Stdin => unreachable!("stdin is read in place"),
+    };
+    match std::fs::read_to_string(path) {
+        Ok(text) => Ok(Input { text, source }),
+        Err(source) => Err(InputError::Io {
+            path: path.clone(),
+            source,
+        }),
+    }
+}
+
+/// Entry point for the day binaries: resolve the input using the first
+/// command line argument as the explicit path, exiting with the error on failure.
+/// `-v` and `-vv` arguments set the [`trace`](crate::trace) level instead.
+pub fn load(day: u8, default: &str) -> String {
+    let (flags, paths): (Vec<_>, Vec<_>) = std::env::args_os()
+        .skip(1)
+        .partition(|a| a == "-v" || a == "-vv");
+    if let Some(level) = flags.iter().map(|f| f.len() as u8 - 1).max() {
+        crate::trace::set_level(level);
+    }
+    let explicit = paths.into_iter().next().map(PathBuf::from);
+    match resolve(day, explicit.as_deref(), default) {
+        Ok(input) => input.text,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod render;
+pub mod solution;
+pub mod trace;
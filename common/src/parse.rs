@@ -0,0 +1,188 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A malformed token in a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    /// The offending token, empty when the line ended too early.
+    pub token: String,
+    /// What the parser was looking for, e.g. "an integer".
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Error on `token`, which must be a subslice of `line` so its column can be recovered.
+    pub fn at(line_no: usize, line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&o| o <= line.len())
+            .unwrap_or(0);
+        ParseError {
+            line: line_no,
+            column: line[..offset].chars().count() + 1,
+            token: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Error on a line that ended before `expected` was found.
+    pub fn end_of_line(line_no: usize, line: &str, expected: impl Into<String>) -> Self {
+        let trimmed = line.trim_end();
+        ParseError::at(line_no, line, &line[trimmed.len()..trimmed.len()], expected)
+    }
+
+    /// Render the error with the offending line of `input` and the token underlined.
+    pub fn render(&self, input: &str) -> String {
+        let text = input
+            .lines()
+            .nth(self.line - 1)
+            .unwrap_or("")
+            .trim_end_matches('\r');
+        let number = self.line.to_string();
+        let pad = " ".repeat(number.len());
+        let underline = "^".repeat(self.token.chars().count().max(1));
+        format!(
+            "error: {}\n{} --> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self.message(),
+            pad,
+            self.line,
+            self.column,
+            pad,
+            number,
+            text,
+            pad,
+            " ".repeat(self.column - 1),
+            underline
+        )
+    }
+
+    fn message(&self) -> String {
+        if self.token.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else {
+            format!("expected {}, found `{}`", self.expected, self.token)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line,
+            self.column,
+            self.message()
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parse `token`, a subslice of `line`, as a number.
+pub fn number<T: FromStr>(line_no: usize, line: &str, token: &str) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::at(line_no, line, token, "an integer"))
+}
+
+/// One line of a puzzle input, borrowed from it, with its 1-based number.
+/// A trailing `\r` is not part of the text, so CRLF inputs parse the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub no: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Error on `token`, a subslice of this line.
+    pub fn error(&self, token: &str, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.no, self.text, token, expected)
+    }
+
+    /// Error on this line ending before `expected`.
+    pub fn end_of_line(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::end_of_line(self.no, self.text, expected)
+    }
+
+    /// Parse `token`, a subslice of this line, as a number.
+    pub fn number<T: FromStr>(&self, token: &str) -> Result<T, ParseError> {
+        number(self.no, self.text, token)
+    }
+
+    /// Whitespace separated fields.
+    pub fn fields(&self) -> std::str::SplitWhitespace<'a> {
+        self.text.split_whitespace()
+    }
+
+    /// Every whitespace separated field as a number.
+    pub fn numbers<T: FromStr>(&self) -> Result<Vec<T>, ParseError> {
+        self.fields().map(|f| self.number(f)).collect()
+    }
+
+    /// The trimmed text on each side of the first `delimiter`, e.g. `47|53`
+    /// or `190: 10 19`. `expected` names the whole shape for the error.
+    pub fn pair(
+        &self,
+        delimiter: char,
+        expected: impl Into<String>,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        match self.text.split_once(delimiter) {
+            Some((a, b)) => Ok((a.trim(), b.trim())),
+            None => Err(self.error(self.text.trim(), expected)),
+        }
+    }
+
+    /// `token`, a subslice of this line, as a list of numbers separated by
+    /// `separator`, e.g. `75,47,61`.
+    pub fn list<T: FromStr>(&self, token: &'a str, separator: char) -> Result<Vec<T>, ParseError> {
+        token
+            .split(separator)
+            .map(|t| self.number(t.trim()))
+            .collect()
+    }
+}
+
+/// Every line of `input`, blank ones included.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        no: i + 1,
+        text: text.strip_suffix('\r').unwrap_or(text),
+    })
+}
+
+/// The lines of `input` that are not blank, so trailing newlines and empty
+/// lines in between are ignored.
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    lines(input).filter(|l| !l.is_blank())
+}
+
+/// Groups of non-blank lines separated by one or more blank lines.
+pub fn sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![];
+    let mut current = vec![];
+    for line in lines(input) {
+        if !line.is_blank() {
+            current.push(line);
+        } else if !current.is_empty() {
+            sections.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Each non-blank line of `input` as its whitespace separated numbers.
+pub fn number_rows<T: FromStr>(input: &str) -> Result<Vec<Vec<T>>, ParseError> {
+    non_blank_lines(input).map(|l| l.numbers()).collect()
+}
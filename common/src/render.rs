@@ -0,0 +1,174 @@
+//! Draw a grid with coloured overlays, as ANSI terminal output or as SVG.
+
+use crate::grid::Grid;
+use std::fmt::{Display, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// Distinct colours for overlays that have no colour of their own, e.g. one
+/// per day 8 frequency. [`Layer::auto`] cycles through them.
+pub const PALETTE: [Rgb; 8] = [
+    Rgb(230, 159, 0),
+    Rgb(86, 180, 233),
+    Rgb(0, 158, 115),
+    Rgb(240, 228, 66),
+    Rgb(0, 114, 178),
+    Rgb(213, 94, 0),
+    Rgb(204, 121, 167),
+    Rgb(153, 153, 153),
+];
+
+/// A set of cells highlighted in one colour. Later layers are drawn over
+/// earlier ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    pub label: String,
+    pub color: Rgb,
+    pub cells: Vec<(usize, usize)>,
+}
+
+impl Layer {
+    pub fn new(
+        label: impl Into<String>,
+        color: Rgb,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Self {
+        Layer {
+            label: label.into(),
+            color,
+            cells: cells.into_iter().collect(),
+        }
+    }
+
+    /// A layer coloured with the `index`th colour of the [`PALETTE`].
+    pub fn auto(
+        label: impl Into<String>,
+        index: usize,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+    ) -> Self {
+        Layer::new(label, PALETTE[index % PALETTE.len()], cells)
+    }
+}
+
+/// Index of the topmost layer covering each cell.
+fn topmost<T>(grid: &Grid<T>, layers: &[Layer]) -> Grid<Option<usize>> {
+    let mut top = Grid::new(grid.width(), grid.height(), None);
+    for (i, layer) in layers.iter().enumerate() {
+        for &(row, col) in &layer.cells {
+            if let Some(cell) = top.get_mut(row, col) {
+                *cell = Some(i);
+            }
+        }
+    }
+    top
+}
+
+/// The grid with highlighted cells on a 24-bit colour background, then one
+/// legend line per layer.
+pub fn ansi<T: Display>(grid: &Grid<T>, layers: &[Layer]) -> String {
+    let top = topmost(grid, layers);
+    let mut out = String::new();
+    for (row, cells) in grid.rows().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            match top[(row, col)] {
+                Some(i) => {
+                    let Rgb(r, g, b) = layers[i].color;
+                    write!(out, "\x1b[30;48;2;{};{};{}m{}\x1b[0m", r, g, b, cell).unwrap();
+                }
+                None => write!(out, "{}", cell).unwrap(),
+            }
+        }
+        out.push('\n');
+    }
+    for layer in layers {
+        let Rgb(r, g, b) = layer.color;
+        writeln!(
+            out,
+            "\x1b[48;2;{};{};{}m  \x1b[0m {} ({} cells)",
+            r,
+            g,
+            b,
+            layer.label,
+            layer.cells.len()
+        )
+        .unwrap();
+    }
+    out
+}
+
+const CELL: usize = 14;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A standalone SVG document of the grid, overlays and legend.
+pub fn svg<T: Display>(grid: &Grid<T>, layers: &[Layer]) -> String {
+    let top = topmost(grid, layers);
+    let (width, height) = (grid.width() * CELL, grid.height() * CELL);
+    let legend = layers.len() * CELL + if layers.is_empty() { 0 } else { CELL / 2 };
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="{}" text-anchor="middle">"#,
+        width.max(200),
+        height + legend,
+        CELL - 3
+    )
+    .unwrap();
+    writeln!(out, r#"<rect width="100%" height="100%" fill="white"/>"#).unwrap();
+    for (row, cells) in grid.rows().enumerate() {
+        for (col, cell) in cells.iter().enumerate() {
+            let (x, y) = (col * CELL, row * CELL);
+            if let Some(i) = top[(row, col)] {
+                writeln!(
+                    out,
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x,
+                    y,
+                    CELL,
+                    CELL,
+                    layers[i].color.hex()
+                )
+                .unwrap();
+            }
+            writeln!(
+                out,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                x + CELL / 2,
+                y + CELL - 3,
+                escape(&cell.to_string())
+            )
+            .unwrap();
+        }
+    }
+    for (i, layer) in layers.iter().enumerate() {
+        let y = height + CELL / 2 + i * CELL;
+        writeln!(
+            out,
+            r#"<rect x="0" y="{}" width="{}" height="{}" fill="{}"/><text x="{}" y="{}" text-anchor="start">{} ({} cells)</text>"#,
+            y,
+            CELL,
+            CELL,
+            layer.color.hex(),
+            CELL + 4,
+            y + CELL - 3,
+            escape(&layer.label),
+            layer.cells.len()
+        )
+        .unwrap();
+    }
+    out.push_str("</svg>\n");
+    out
+}
@@ -0,0 +1,117 @@
+use crate::parse::ParseError;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::fmt;
+
+/// The answer to one part of a puzzle, serialized as a bare number or string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Str(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Str(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Int(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, u32, i64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// Why a part has no answer for an input that parsed, e.g. a total too large
+/// to compute.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError(pub String);
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// One day's puzzle: parse the input once, then solve each part from it.
+pub trait Solution {
+    const DAY: u8;
+    /// Input file used when none is given, see [`crate::input::resolve`].
+    const DEFAULT_INPUT: &'static str;
+    /// Name of the algorithm, for days that keep more than one implementation.
+    const VARIANT: &'static str = "default";
+    type Input: Send + Sync + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+}
+
+/// A parsed input whose type is only known to the solution that produced it.
+pub type Parsed = Box<dyn Any + Send + Sync>;
+
+/// Object-safe form of [`Solution`], so solutions of different days can sit
+/// in one registry. Every `Solution` gets it for free.
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u8;
+    fn variant(&self) -> &'static str;
+    fn default_input(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Parsed, ParseError>;
+    /// Solve `part` (1 or 2) of an input returned by [`DynSolution::parse`] of the same solution.
+    fn solve(&self, parsed: &Parsed, part: u8) -> Result<Answer, SolveError>;
+}
+
+impl<S: Solution + Send + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn variant(&self) -> &'static str {
+        S::VARIANT
+    }
+
+    fn default_input(&self) -> &'static str {
+        S::DEFAULT_INPUT
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve(&self, parsed: &Parsed, part: u8) -> Result<Answer, SolveError> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by another solution");
+        match part {
+            1 => S::part1(input),
+            2 => S::part2(input),
+            _ => panic!("day {} has no part {}", S::DAY, part),
+        }
+    }
+}
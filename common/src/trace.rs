@@ -0,0 +1,41 @@
+//! Opt-in tracing of the decisions the solvers make, printed to stderr.
+//!
+//! Solvers call [`trace!`](crate::trace!) with a level: 1 for the decisions
+//! behind an answer (`-v`), 2 for every step taken (`-vv`). A message is only
+//! formatted when its level is enabled, and without the `trace` feature the
+//! calls compile to nothing.
+
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static LEVEL: AtomicU8 = AtomicU8::new(0);
+
+/// Enable the messages up to `level`, 0 turns tracing off.
+pub fn set_level(level: u8) {
+    LEVEL.store(level, Ordering::Relaxed);
+}
+
+#[inline(always)]
+pub fn enabled(level: u8) -> bool {
+    cfg!(feature = "trace") && LEVEL.load(Ordering::Relaxed) >= level
+}
+
+/// Print a message of `module`, prefixed with its crate, e.g. `[day5]`.
+/// Kept out of line so the disabled branch does not weigh on hot loops.
+#[cold]
+#[inline(never)]
+pub fn emit(module: &str, args: fmt::Arguments) {
+    let krate = module.split("::").next().unwrap_or(module);
+    eprintln!("[{}] {}", krate, args);
+}
+
+/// `trace!(level, "format", args...)`: print the message when tracing at
+/// `level` or above. The arguments are not evaluated otherwise.
+#[macro_export]
+macro_rules! trace {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::trace::enabled($level) {
+            $crate::trace::emit(module_path!(), format_args!($($arg)*));
+        }
+    };
+}
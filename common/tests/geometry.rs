@@ -0,0 +1,44 @@
+use common::geometry::{Dir8, Direction, Point};
+
+#[test]
+fn reduce_keeps_signs_and_zero_components() {
+    assert_eq!(Point::new(4, 6).reduce(), Point::new(2, 3));
+    assert_eq!(Point::new(-4, 6).reduce(), Point::new(-2, 3));
+    assert_eq!(Point::new(-9, -3).reduce(), Point::new(-3, -1));
+    assert_eq!(Point::new(0, -5).reduce(), Point::new(0, -1));
+    assert_eq!(Point::new(7, 0).reduce(), Point::new(1, 0));
+    assert_eq!(Point::ZERO.reduce(), Point::ZERO);
+}
+
+#[test]
+fn turns_round_trip() {
+    for d in Direction::ALL {
+        assert_eq!(d.turn_right().turn_left(), d);
+        assert_eq!(d.turn_right().turn_right(), d.opposite());
+        assert_eq!(d.opposite().opposite(), d);
+        assert_eq!((0..4).fold(d, |d, _| d.turn_right()), d);
+        assert_eq!(d.opposite().delta(), -d.delta());
+    }
+    for d in Dir8::ALL {
+        assert_eq!(d.turn_left().turn_right(), d);
+        assert_eq!(d.opposite().delta(), -d.delta());
+        assert_eq!(d.turn_right().is_diagonal(), d.is_diagonal());
+    }
+}
+
+#[test]
+fn dir8_agrees_with_direction() {
+    for d in Direction::ALL {
+        let d8 = Dir8::from(d);
+        assert!(!d8.is_diagonal());
+        assert_eq!(Point::from(d8), Point::from(d));
+        assert_eq!(Dir8::from(d.turn_right()), d8.turn_right());
+    }
+    assert_eq!(Dir8::from(Direction::Left), Dir8::W);
+
+    let diagonals = Dir8::ALL.into_iter().filter(|d| d.is_diagonal());
+    for d in diagonals {
+        let Point { row, col } = d.delta();
+        assert_eq!((row.abs(), col.abs()), (1, 1), "{:?}", d);
+    }
+}
@@ -0,0 +1,60 @@
+use common::geometry::{Dir8, Direction};
+use common::grid::{Grid, GridError};
+
+#[test]
+fn ragged_rows_are_parse_errors() {
+    let e = Grid::parse("abc\nabcd\nabc\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 4, "d"));
+    assert_eq!(e.expected, "end of row");
+
+    let e = Grid::parse("abc\nab\nabc\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 3, ""));
+    assert_eq!(e.expected, "a row of 3 cells");
+
+    let e = Grid::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+    assert_eq!(
+        e,
+        GridError::Ragged {
+            row: 1,
+            expected: 2,
+            found: 1
+        }
+    );
+}
+
+#[test]
+fn rays_stop_at_the_edge() {
+    let grid = Grid::parse("abc\ndef\nghi\n").unwrap();
+    let cells = |start, delta: Dir8| grid.ray(start, delta).map(|(_, &c)| c).collect::<String>();
+
+    assert_eq!(cells((0, 0), Dir8::E), "bc");
+    assert_eq!(cells((0, 0), Dir8::SE), "ei");
+    assert_eq!(cells((2, 1), Dir8::N), "eb");
+    assert_eq!(cells((0, 0), Dir8::NW), "");
+    assert_eq!(cells((1, 2), Dir8::E), "");
+
+    let last = grid.ray((1, 0), Direction::Right).last();
+    assert_eq!(last, Some(((1, 2), &'f')));
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::new(3, 3, 0);
+    for (pos, four, eight) in [
+        ((0, 0), 2, 3),
+        ((2, 2), 2, 3),
+        ((0, 1), 3, 5),
+        ((1, 2), 3, 5),
+        ((1, 1), 4, 8),
+    ] {
+        assert_eq!(grid.neighbours4(pos).count(), four, "{:?}", pos);
+        assert_eq!(grid.neighbours8(pos).count(), eight, "{:?}", pos);
+    }
+
+    let mut corner = grid.neighbours8((0, 0)).collect::<Vec<_>>();
+    corner.sort_unstable();
+    assert_eq!(corner, vec![(0, 1), (1, 0), (1, 1)]);
+
+    let single = Grid::new(1, 1, 0);
+    assert_eq!(single.neighbours8((0, 0)).count(), 0);
+}
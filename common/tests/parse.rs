@@ -0,0 +1,70 @@
+use common::parse::{self, Line};
+
+#[test]
+fn lines_drop_carriage_returns_and_keep_numbers() {
+    let lines = parse::lines("3   4\r\n\r\n4   3\r").collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        vec![
+            Line {
+                no: 1,
+                text: "3   4"
+            },
+            Line { no: 2, text: "" },
+            Line {
+                no: 3,
+                text: "4   3"
+            },
+        ]
+    );
+}
+
+#[test]
+fn number_rows_ignore_blank_and_trailing_lines() {
+    let rows = parse::number_rows::<u32>("7 6 4\n\n1 2\n\n\n").unwrap();
+    assert_eq!(rows, vec![vec![7, 6, 4], vec![1, 2]]);
+
+    let e = parse::number_rows::<u32>("7 6 4\n1 x 2\n").unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (2, 3, "x"));
+}
+
+#[test]
+fn sections_split_on_runs_of_blank_lines() {
+    let input = "\r\n47|53\r\n97|13\r\n\r\n\r\n75,47\r\n";
+    let sections = parse::sections(input);
+    assert_eq!(sections.len(), 2);
+    assert_eq!(sections[0].len(), 2);
+    assert_eq!(
+        sections[1][0],
+        Line {
+            no: 6,
+            text: "75,47"
+        }
+    );
+}
+
+#[test]
+fn pairs_and_lists_borrow_from_the_line() {
+    let line = Line {
+        no: 1,
+        text: "190: 10 19",
+    };
+    let (value, numbers) = line.pair(':', "an equation").unwrap();
+    assert_eq!((value, numbers), ("190", "10 19"));
+    assert_eq!(line.number::<i64>(value).unwrap(), 190);
+
+    let line = Line {
+        no: 4,
+        text: "75, 47,x",
+    };
+    let e = line.list::<i32>(line.text, ',').unwrap_err();
+    assert_eq!((e.line, e.column, e.token.as_str()), (4, 8, "x"));
+
+    let e = Line {
+        no: 2,
+        text: " 47 53",
+    }
+    .pair('|', "a rule `X|Y`")
+    .unwrap_err();
+    assert_eq!((e.column, e.token.as_str()), (2, "47 53"));
+}
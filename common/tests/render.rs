@@ -0,0 +1,33 @@
+use common::grid::Grid;
+use common::render::{self, Layer, Rgb};
+
+fn drawing() -> (Grid<char>, Vec<Layer>) {
+    let grid = Grid::parse("ab\ncd\n").unwrap();
+    let layers = vec![
+        Layer::new("first", Rgb(255, 0, 0), [(0, 0), (1, 1)]),
+        Layer::new("second & last", Rgb(0, 0, 255), [(1, 1)]),
+    ];
+    (grid, layers)
+}
+
+#[test]
+fn ansi_colours_covered_cells_with_the_topmost_layer() {
+    let (grid, layers) = drawing();
+    let out = render::ansi(&grid, &layers);
+    let lines = out.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "\x1b[30;48;2;255;0;0ma\x1b[0mb");
+    assert_eq!(lines[1], "c\x1b[30;48;2;0;0;255md\x1b[0m");
+    assert!(lines[2].ends_with("first (2 cells)"));
+    assert!(lines[3].ends_with("second & last (1 cells)"));
+}
+
+#[test]
+fn svg_draws_cells_and_escapes_labels() {
+    let (grid, layers) = drawing();
+    let out = render::svg(&grid, &layers);
+    assert!(out.starts_with("<svg "));
+    assert!(out.trim_end().ends_with("</svg>"));
+    assert_eq!(out.matches("<text").count(), 4 + 2);
+    assert!(out.contains(r##"fill="#0000ff""##));
+    assert!(out.contains("second &amp; last"));
+}
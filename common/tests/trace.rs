@@ -0,0 +1,21 @@
+use common::trace;
+use std::cell::Cell;
+
+#[test]
+fn messages_above_the_level_are_not_formatted() {
+    let evaluated = Cell::new(false);
+    let arg = || {
+        evaluated.set(true);
+        0
+    };
+
+    trace::set_level(1);
+    common::trace!(2, "{}", arg());
+    assert!(!evaluated.get());
+    assert!(!trace::enabled(2));
+
+    trace::set_level(0);
+    common::trace!(1, "{}", arg());
+    assert!(!evaluated.get());
+    assert!(!trace::enabled(1));
+}
@@ -0,0 +1,320 @@
+use common::parse::{self, ParseError};
+use common::solution::{Answer, DynSolution, Parsed, Solution, SolveError};
+use common::trace;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+pub mod metrics;
+
+pub const DAY: u8 = 1;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/part1.txt");
+
+/// The location lists, one per column, in input order. The puzzle has two,
+/// any further ones are compared with [`matrix`].
+pub struct Input {
+    pub columns: Vec<Vec<i64>>,
+    /// Line each number of `columns` was read from, 1-based. Padding was
+    /// read from nowhere, so a padded column has fewer lines than numbers.
+    pub lines: Vec<Vec<usize>>,
+    /// How columns of different lengths were evened out.
+    pub unequal: Unequal,
+    /// Numbers read in each column, before truncating or padding.
+    pub read: Vec<usize>,
+}
+
+impl Input {
+    pub fn firsts(&self) -> &[i64] {
+        &self.columns[0]
+    }
+
+    pub fn seconds(&self) -> &[i64] {
+        &self.columns[1]
+    }
+}
+
+/// Which policy evened the columns out, and what it had to work with.
+pub fn unequal_note(input: &Input) -> String {
+    let read = input.read.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+    if input.read.windows(2).all(|w| w[0] == w[1]) {
+        format!(
+            "unequal columns: {}, not needed as every column has {} numbers",
+            input.unequal,
+            read.first().map_or("0", String::as_str)
+        )
+    } else {
+        format!(
+            "unequal columns: {}, the columns had {} numbers",
+            input.unequal,
+            read.join(", ")
+        )
+    }
+}
+
+/// What to do when some lines lack their last numbers, so the columns they
+/// end up in are shorter than the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Unequal {
+    /// Reject the input at the first short line.
+    #[default]
+    Error,
+    /// Drop the numbers past the end of the shortest column, in input order.
+    Truncate,
+    /// Fill the shorter columns up with a value.
+    Pad(i64),
+}
+
+impl fmt::Display for Unequal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unequal::Error => write!(f, "error"),
+            Unequal::Truncate => write!(f, "truncate"),
+            Unequal::Pad(value) => write!(f, "pad with {}", value),
+        }
+    }
+}
+
+/// Parse with the [`Unequal::Error`] policy: every line must have as many
+/// numbers as the widest one, and there must be at least two columns.
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    parse_with(input, Unequal::Error)
+}
+
+/// Parse with `unequal` deciding what happens to lines with fewer numbers
+/// than the widest one.
+pub fn parse_with(input: &str, unequal: Unequal) -> Result<Input, ParseError> {
+    let rows = parse::non_blank_lines(input)
+        .map(|line| Ok((line, line.numbers::<i64>()?)))
+        .collect::<Result<Vec<_>, ParseError>>()?;
+    let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(2);
+    if width < 2 {
+        return Err(rows[0].0.end_of_line("a second number"));
+    }
+    let widest = rows.iter().find(|(_, row)| row.len() == width);
+
+    let mut columns = vec![vec![]; width];
+    let mut lines = vec![vec![]; width];
+    for (line, row) in rows.iter() {
+        if row.len() < width && unequal == Unequal::Error {
+            let widest = widest.map_or(0, |(l, _)| l.no);
+            return Err(line.end_of_line(format!("{} numbers as on line {}", width, widest)));
+        }
+        for (i, &n) in row.iter().enumerate() {
+            columns[i].push(n);
+            lines[i].push(line.no);
+        }
+    }
+
+    let read = columns.iter().map(Vec::len).collect::<Vec<_>>();
+    match unequal {
+        Unequal::Error => {}
+        Unequal::Truncate => {
+            let shortest = read.iter().copied().min().unwrap_or(0);
+            for (column, lines) in columns.iter_mut().zip(lines.iter_mut()) {
+                column.truncate(shortest);
+                lines.truncate(shortest);
+            }
+        }
+        Unequal::Pad(value) => {
+            let longest = read.iter().copied().max().unwrap_or(0);
+            for column in columns.iter_mut() {
+                column.resize(longest, value);
+            }
+        }
+    }
+    Ok(Input {
+        columns,
+        lines,
+        unequal,
+        read,
+    })
+}
+
+/// Integer types a location list can hold. Totals are computed on `i128`,
+/// which holds any of them.
+pub trait LocationId: Copy + Ord + Hash + fmt::Display {
+    fn widen(self) -> i128;
+}
+
+macro_rules! location_id {
+    ($($t:ty),*) => {
+        $(
+            impl LocationId for $t {
+                fn widen(self) -> i128 {
+                    self as i128
+                }
+            }
+        )*
+    };
+}
+
+location_id!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// A total that does not fit in an `i128`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the total overflows a 128-bit integer")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Sum of the distances between the smallest of each list, the second
+/// smallest, and so on. The largest numbers of the longer list are left out,
+/// see [`Unequal`] to decide what happens to lists of different lengths.
+pub fn total_distance<T: LocationId>(firsts: &[T], seconds: &[T]) -> Result<i128, Overflow> {
+    let mut firsts = firsts.to_vec();
+    let mut seconds = seconds.to_vec();
+
+    firsts.sort_unstable();
+    seconds.sort_unstable();
+
+    firsts
+        .into_iter()
+        .zip(seconds)
+        .try_fold(0i128, |total, (first, second)| {
+            let distance = second
+                .widen()
+                .checked_sub(first.widen())
+                .and_then(i128::checked_abs)
+                .ok_or(Overflow)?;
+            trace!(2, "{} and {} are {} apart", first, second, distance);
+            total.checked_add(distance).ok_or(Overflow)
+        })
+}
+
+/// Sum of each number of `firsts` times the number of times it appears in
+/// `seconds`.
+pub fn similarity_score<T: LocationId>(firsts: &[T], seconds: &[T]) -> Result<i128, Overflow> {
+    let mut occurrences: HashMap<T, i128> = HashMap::new();
+    for &num in seconds.iter() {
+        *occurrences.entry(num).or_default() += 1;
+    }
+    firsts.iter().try_fold(0i128, |score, num| {
+        let count = occurrences.get(num).copied().unwrap_or(0);
+        trace!(2, "{} appears {} times on the right", num, count);
+        num.widen()
+            .checked_mul(count)
+            .and_then(|n| score.checked_add(n))
+            .ok_or(Overflow)
+    })
+}
+
+/// A left and a right location ID that part 1 matches by sorting both lists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pairing {
+    pub left: i64,
+    pub right: i64,
+    /// Lines of the input the two IDs were read from, `None` for padding.
+    pub left_line: Option<usize>,
+    pub right_line: Option<usize>,
+    pub distance: i128,
+}
+
+/// The pairs behind part 1, largest distance first. Pairs at the same
+/// distance, and equal IDs within a list, keep their input order.
+pub fn pairings(input: &Input) -> Vec<Pairing> {
+    let sorted = |column: usize| {
+        let mut rows = input.columns[column]
+            .iter()
+            .enumerate()
+            .map(|(i, &value)| (value, input.lines[column].get(i).copied()))
+            .collect::<Vec<_>>();
+        rows.sort_by_key(|&(value, _)| value);
+        rows
+    };
+    let mut pairs = sorted(0)
+        .into_iter()
+        .zip(sorted(1))
+        .map(|((left, left_line), (right, right_line))| Pairing {
+            left,
+            right,
+            left_line,
+            right_line,
+            distance: (right as i128 - left as i128).abs(),
+        })
+        .collect::<Vec<_>>();
+    pairs.sort_by_key(|p| std::cmp::Reverse(p.distance));
+    pairs
+}
+
+/// `metric` of every ordered pair of columns: row `i`, column `j` holds
+/// `metric(columns[i], columns[j])`.
+pub fn matrix<T: LocationId>(
+    columns: &[Vec<T>],
+    metric: impl Fn(&[T], &[T]) -> Result<i128, Overflow>,
+) -> Result<Vec<Vec<i128>>, Overflow> {
+    columns
+        .iter()
+        .map(|a| columns.iter().map(|b| metric(a, b)).collect())
+        .collect()
+}
+
+pub fn part1(input: &Input) -> Result<i128, Overflow> {
+    total_distance(input.firsts(), input.seconds())
+}
+
+pub fn part2(input: &Input) -> Result<i128, Overflow> {
+    similarity_score(input.firsts(), input.seconds())
+}
+
+impl From<Overflow> for SolveError {
+    fn from(e: Overflow) -> Self {
+        SolveError(e.to_string())
+    }
+}
+
+/// The answer, spelled out when it is too large for an `Answer::Int`.
+fn answer(total: Result<i128, Overflow>) -> Result<Answer, SolveError> {
+    let total = total?;
+    Ok(i64::try_from(total).map_or_else(|_| total.to_string().into(), Answer::Int))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        answer(part1(input))
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        answer(part2(input))
+    }
+}
+
+/// `Day1` parsing with another policy than [`Unequal::Error`]. The policy is
+/// a value, not a constant, so this implements [`DynSolution`] itself.
+pub struct Day1With(pub Unequal);
+
+impl DynSolution for Day1With {
+    fn day(&self) -> u8 {
+        DAY
+    }
+
+    fn variant(&self) -> &'static str {
+        Day1.variant()
+    }
+
+    fn default_input(&self) -> &'static str {
+        DEFAULT_INPUT
+    }
+
+    fn parse(&self, input: &str) -> Result<Parsed, ParseError> {
+        Ok(Box::new(parse_with(input, self.0)?))
+    }
+
+    fn solve(&self, parsed: &Parsed, part: u8) -> Result<Answer, SolveError> {
+        Day1.solve(parsed, part)
+    }
+}
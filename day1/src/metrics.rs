@@ -0,0 +1,206 @@
+//! Other ways than the part 1 total to score how far apart two location
+//! lists are, and the optimal assignment of one list to the other for pair
+//! costs under which sorting both lists is no longer the best matching.
+
+/// Scores a matching of two lists, given as `(left, right)` pairs. Lower
+/// means closer.
+pub trait Metric {
+    fn name(&self) -> &str;
+    fn score(&self, pairs: &[(i64, i64)]) -> f64;
+}
+
+fn distance(a: i64, b: i64) -> f64 {
+    (b as i128 - a as i128).abs() as f64
+}
+
+/// Sum of the distances, the part 1 answer.
+pub struct L1;
+
+/// Sum of the squared distances.
+pub struct L2;
+
+/// Largest distance.
+pub struct LInf;
+
+/// Mean distance over the range the values span, 0 when the pairs match
+/// exactly and at most 1. Of the sorted pairs, it is the 1-Wasserstein
+/// distance between the two lists taken as distributions.
+pub struct Wasserstein;
+
+impl Metric for L1 {
+    fn name(&self) -> &str {
+        "L1"
+    }
+
+    fn score(&self, pairs: &[(i64, i64)]) -> f64 {
+        pairs.iter().map(|&(a, b)| distance(a, b)).sum()
+    }
+}
+
+impl Metric for L2 {
+    fn name(&self) -> &str {
+        "L2 (squared)"
+    }
+
+    fn score(&self, pairs: &[(i64, i64)]) -> f64 {
+        pairs.iter().map(|&(a, b)| distance(a, b).powi(2)).sum()
+    }
+}
+
+impl Metric for LInf {
+    fn name(&self) -> &str {
+        "L-infinity"
+    }
+
+    fn score(&self, pairs: &[(i64, i64)]) -> f64 {
+        pairs
+            .iter()
+            .map(|&(a, b)| distance(a, b))
+            .fold(0.0, f64::max)
+    }
+}
+
+impl Metric for Wasserstein {
+    fn name(&self) -> &str {
+        "Wasserstein (normalised)"
+    }
+
+    fn score(&self, pairs: &[(i64, i64)]) -> f64 {
+        let values = pairs.iter().flat_map(|&(a, b)| [a, b]);
+        let (Some(min), Some(max)) = (values.clone().min(), values.max()) else {
+            return 0.0;
+        };
+        let range = distance(min, max);
+        if range == 0.0 {
+            return 0.0;
+        }
+        L1.score(pairs) / pairs.len() as f64 / range
+    }
+}
+
+/// The built-in metrics, in the order they are reported.
+pub static METRICS: [&(dyn Metric + Sync); 4] = [&L1, &L2, &LInf, &Wasserstein];
+
+/// The smallest of each list with the smallest of the other, and so on, as
+/// part 1 pairs them. The longer list's largest values are left out.
+pub fn sorted_pairs(firsts: &[i64], seconds: &[i64]) -> Vec<(i64, i64)> {
+    let mut firsts = firsts.to_vec();
+    let mut seconds = seconds.to_vec();
+    firsts.sort_unstable();
+    seconds.sort_unstable();
+    firsts.into_iter().zip(seconds).collect()
+}
+
+/// `|right - left|` raised to `exponent`. Below 1 the cost is concave, so
+/// two moderate distances can cost more than a large one and an exact
+/// match, and sorting both lists no longer minimises the total.
+pub fn power_cost(exponent: f64) -> impl Fn(i64, i64) -> f64 {
+    move |a, b| distance(a, b).powf(exponent)
+}
+
+/// Total `cost` of `pairs`.
+pub fn cost_of(pairs: &[(i64, i64)], cost: impl Fn(i64, i64) -> f64) -> f64 {
+    pairs.iter().map(|&(a, b)| cost(a, b)).sum()
+}
+
+/// The pairs of lowest total `cost` matching every value of the shorter list
+/// with a different value of the longer one, by the Hungarian algorithm. It
+/// takes O(n²) memory and O(n³) time, a fraction of a second in a release
+/// build for a puzzle input.
+pub fn optimal_assignment(
+    firsts: &[i64],
+    seconds: &[i64],
+    cost: impl Fn(i64, i64) -> f64,
+) -> Vec<(i64, i64)> {
+    // rows are the shorter list
+    let swap = firsts.len() > seconds.len();
+    let (rows, columns) = if swap {
+        (seconds, firsts)
+    } else {
+        (firsts, seconds)
+    };
+    let costs = rows
+        .iter()
+        .map(|&r| {
+            columns
+                .iter()
+                .map(|&c| if swap { cost(c, r) } else { cost(r, c) })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    hungarian(&costs, columns.len())
+        .into_iter()
+        .enumerate()
+        .map(|(i, j)| {
+            if swap {
+                (columns[j], rows[i])
+            } else {
+                (rows[i], columns[j])
+            }
+        })
+        .collect()
+}
+
+/// Column assigned to each row of `costs`, `rows <= columns`, with row and
+/// column potentials kept 1-based so index 0 can stand for "unassigned".
+fn hungarian(costs: &[Vec<f64>], columns: usize) -> Vec<usize> {
+    let (n, m) = (costs.len(), columns);
+    let mut u = vec![0.0; n + 1];
+    let mut v = vec![0.0; m + 1];
+    // row matched to each column, and the previous column on its
+    // augmenting path
+    let mut row_of = vec![0; m + 1];
+    let mut way = vec![0; m + 1];
+
+    for i in 1..=n {
+        row_of[0] = i;
+        let mut j0 = 0;
+        let mut min = vec![f64::INFINITY; m + 1];
+        let mut used = vec![false; m + 1];
+        loop {
+            used[j0] = true;
+            let i0 = row_of[j0];
+            let (mut delta, mut j1) = (f64::INFINITY, 0);
+            for j in 1..=m {
+                if used[j] {
+                    continue;
+                }
+                let reduced = costs[i0 - 1][j - 1] - u[i0] - v[j];
+                if reduced < min[j] {
+                    min[j] = reduced;
+                    way[j] = j0;
+                }
+                if min[j] < delta {
+                    delta = min[j];
+                    j1 = j;
+                }
+            }
+            for j in 0..=m {
+                if used[j] {
+                    u[row_of[j]] += delta;
+                    v[j] -= delta;
+                } else {
+                    min[j] -= delta;
+                }
+            }
+            j0 = j1;
+            if row_of[j0] == 0 {
+                break;
+            }
+        }
+        // flip the augmenting path
+        while j0 != 0 {
+            let j1 = way[j0];
+            row_of[j0] = row_of[j1];
+            j0 = j1;
+        }
+    }
+
+    let mut assigned = vec![0; n];
+    for j in 1..=m {
+        if row_of[j] != 0 {
+            assigned[row_of[j] - 1] = j - 1;
+        }
+    }
+    assigned
+}
@@ -0,0 +1,133 @@
+use common::parse::{self, ParseError};
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+
+pub const DAY: u8 = 2;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/input.txt");
+
+/// One report per non-blank line.
+pub struct Input {
+    pub reports: Vec<Vec<u32>>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    Ok(Input {
+        reports: parse::number_rows(input)?,
+    })
+}
+
+/// A report is safe when its levels only increase or only decrease, by 1 to
+/// 3 at each step.
+pub fn report_is_valid(report: &[u32]) -> bool {
+    if report.len() == 1 {
+        return true;
+    }
+    // check ascending or descending order
+    let is_ascending = report.windows(2).all(|w| w[0] <= w[1]);
+    let is_descending = report.windows(2).all(|w| w[0] >= w[1]);
+    if !is_ascending && !is_descending {
+        return false;
+    }
+
+    // check diff is within range [1,3]
+    let is_valid_range = report
+        .windows(2)
+        .map(|w| w[1] as i32 - w[0] as i32)
+        .all(|x| x.abs() >= 1 && x.abs() <= 3);
+
+    is_valid_range
+}
+
+/// Why `report_is_valid` rejects `report`, for tracing.
+fn unsafe_reason(report: &[u32]) -> String {
+    let is_ascending = report.windows(2).all(|w| w[0] <= w[1]);
+    let is_descending = report.windows(2).all(|w| w[0] >= w[1]);
+    if !is_ascending && !is_descending {
+        return "levels both increase and decrease".to_string();
+    }
+    match report
+        .windows(2)
+        .find(|w| !(1..=3).contains(&w[0].abs_diff(w[1])))
+    {
+        Some(w) => format!("{} and {} differ by {}", w[0], w[1], w[0].abs_diff(w[1])),
+        None => "safe".to_string(),
+    }
+}
+
+pub fn part1(input: &Input) -> u32 {
+    let res: usize = input
+        .reports
+        .iter()
+        .filter(|report| {
+            let valid = report_is_valid(report);
+            if !valid {
+                trace!(
+                    1,
+                    "report {:?} is unsafe: {}",
+                    report,
+                    unsafe_reason(report)
+                );
+            }
+            valid
+        })
+        .count();
+
+    res as u32
+}
+
+/// Safe, or safe once a single level is removed (the Problem Dampener).
+pub fn report_is_tolerable(report: &[u32]) -> bool {
+    if report_is_valid(report) {
+        return true;
+    }
+    for i in 0..report.len() {
+        let report_copy = [&report[0..i], &report[i + 1..]].concat();
+        if report_is_valid(&report_copy) {
+            trace!(
+                1,
+                "report {:?} is safe without level {} ({})",
+                report,
+                i + 1,
+                report[i]
+            );
+            return true;
+        }
+    }
+    trace!(
+        1,
+        "report {:?} is unsafe whichever level is removed: {}",
+        report,
+        unsafe_reason(report)
+    );
+    false
+}
+
+pub fn part2(input: &Input) -> u32 {
+    let res: usize = input
+        .reports
+        .iter()
+        .filter(|report| report_is_tolerable(report))
+        .count();
+
+    res as u32
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
@@ -0,0 +1,88 @@
+use common::parse::ParseError;
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+use regex::Regex;
+
+pub const DAY: u8 = 3;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/input.txt");
+
+/// The corrupted memory is matched as it is, any text is a valid input.
+pub fn parse(input: &str) -> Result<String, ParseError> {
+    Ok(input.to_string())
+}
+
+pub fn part1(input: &str) -> u32 {
+    // let re = Regex::new(r"mul\([0-9]+,[0-9]+\)").unwrap();
+    // let re = Regex::new(r"mul\((?<op1>[0-9]+),(?<op2>[0-9]+)\)").unwrap();
+    let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
+
+    // re.find_iter(input)
+    //     .map(|m| {
+    //         m.as_str()[4..m.len() - 1]
+    //             .split(",")
+    //             .map(|n| n.parse::<u32>().unwrap())
+    //             .product::<u32>()
+    //     })
+    //     .sum()
+
+    let mut res = 0;
+    // for c in re.captures_iter(input) {
+    //     res += &c["op1"].parse::<u32>().unwrap() * &c["op2"].parse::<u32>().unwrap();
+    // }
+    for (m, [op1, op2]) in re.captures_iter(input).map(|c| c.extract()) {
+        let product = op1.parse::<u32>().unwrap() * op2.parse::<u32>().unwrap();
+        trace!(2, "`{}` adds {}", m, product);
+        res += product
+    }
+    res
+}
+pub fn part2(input: &str) -> i32 {
+    let re = Regex::new(r"(mul\((?<op1>[0-9]+),(?<op2>[0-9]+)\))|(?<dt>don't\(\))|(?<d>do\(\))")
+        .unwrap();
+
+    let mut res = 0;
+    let mut valid = true;
+    for m in re.captures_iter(input) {
+        let at = m.get(0).unwrap().start();
+        if m.name("d").is_some() {
+            if !valid {
+                trace!(1, "`do()` at byte {} enables the instructions", at);
+            }
+            valid = true;
+        } else if m.name("dt").is_some() {
+            if valid {
+                trace!(1, "`don't()` at byte {} disables the instructions", at);
+            }
+            valid = false;
+        }
+        if let (Some(op1), Some(op2)) = (m.name("op1"), m.name("op2")) {
+            if valid {
+                trace!(2, "`{}` at byte {} is counted", &m[0], at);
+                res += op1.as_str().parse::<i32>().unwrap() * op2.as_str().parse::<i32>().unwrap();
+            } else {
+                trace!(1, "`{}` at byte {} is disabled", &m[0], at);
+            }
+        }
+    }
+    res
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &String) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &String) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
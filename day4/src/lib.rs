@@ -0,0 +1,123 @@
+use common::geometry::Dir8;
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+use std::collections::HashSet;
+
+pub const DAY: u8 = 4;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/inputs.txt");
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Occurrences of `pattern` right after `start`, going right, down or along
+/// either downward diagonal. Part 1 checks each word and its reverse, so the
+/// four remaining directions are covered.
+pub fn find_pattern(start: (usize, usize), map: &Grid<char>, pattern: &[char]) -> i32 {
+    // 4 possible directions:
+    // right, down, leftdown, rightdown
+    let dirs = [Dir8::E, Dir8::S, Dir8::SW, Dir8::SE];
+
+    dirs.iter()
+        .filter(|&&dir| {
+            let found = map
+                .ray(start, dir)
+                .map(|(_, c)| c)
+                .take(pattern.len())
+                .eq(pattern.iter());
+            if found {
+                trace!(2, "{:?} from {:?} going {:?}", pattern, start, dir);
+            }
+            found
+        })
+        .count() as i32
+}
+
+pub fn part1(map: &Grid<char>) -> i32 {
+    let pairs = [('X', ['M', 'A', 'S']), ('S', ['A', 'M', 'X'])];
+    pairs
+        .into_iter()
+        .map(|(start_char, pattern)| {
+            map.positions_of(&start_char)
+                .map(|start| find_pattern(start, map, &pattern))
+                .sum::<i32>()
+        })
+        .sum()
+}
+
+/// 1 when the `A` at `start` is the centre of two diagonal `MAS`, else 0.
+pub fn find_pattern2(start: (usize, usize), map: &Grid<char>) -> i32 {
+    // possible postions of M, S sits on the opposite corner
+    let dirs = [Dir8::NW, Dir8::SW, Dir8::SE, Dir8::NE];
+
+    (dirs
+        .iter()
+        .filter(|&&d| {
+            map.step(start, d).is_some_and(|p| map[p] == 'M')
+                && map.step(start, d.opposite()).is_some_and(|p| map[p] == 'S')
+        })
+        .count()
+        / 2) as i32
+}
+
+pub fn part2(map: &Grid<char>) -> i32 {
+    map.positions_of(&'A')
+        .map(|start| {
+            let found = find_pattern2(start, map);
+            if found > 0 {
+                trace!(2, "X-MAS centred at {:?}", start);
+            }
+            found
+        })
+        .sum()
+}
+
+/// Cells of every `XMAS` counted by `part1`, read in any of the 8 directions.
+pub fn xmas_cells(map: &Grid<char>) -> HashSet<(usize, usize)> {
+    let mut cells = HashSet::new();
+    for start in map.positions_of(&'X') {
+        for dir in Dir8::ALL {
+            let word = map.ray(start, dir).take(4).collect::<Vec<_>>();
+            if word.iter().map(|(_, &c)| c).eq("XMAS".chars()) {
+                cells.extend(word.iter().map(|&(p, _)| p));
+            }
+        }
+    }
+    cells
+}
+
+/// Cells of every `MAS` cross counted by `part2`.
+pub fn x_mas_cells(map: &Grid<char>) -> HashSet<(usize, usize)> {
+    let mut cells = HashSet::new();
+    for centre in map.positions_of(&'A') {
+        if find_pattern2(centre, map) > 0 {
+            cells.insert(centre);
+            cells.extend(
+                [Dir8::NW, Dir8::SW, Dir8::SE, Dir8::NE].map(|d| map.step(centre, d).unwrap()),
+            );
+        }
+    }
+    cells
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
@@ -0,0 +1,207 @@
+use common::parse::{self, ParseError};
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: u8 = 5;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/example.txt");
+
+/// Page ordering rules and the updates to check against them.
+pub struct Input {
+    /// `(x, y)`: page x needs to be printed before page y.
+    pub rules: Vec<(i32, i32)>,
+    pub updates: Vec<Vec<i32>>,
+}
+
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    // rules come first, a blank line separates them from the updates
+    let sections = parse::sections(input);
+    let (rules, updates) = match sections.split_first() {
+        Some((rules, updates)) => (rules.as_slice(), updates.concat()),
+        None => (&[][..], vec![]),
+    };
+    Ok(Input {
+        rules: rules
+            .iter()
+            .map(|line| {
+                let (x, y) = line.pair('|', "a rule `X|Y`")?;
+                Ok((line.number(x)?, line.number(y)?))
+            })
+            .collect::<Result<_, ParseError>>()?,
+        updates: updates
+            .iter()
+            .map(|line| line.list(line.text, ','))
+            .collect::<Result<_, _>>()?,
+    })
+}
+
+/// The pages of `update` in an order that respects the rules, by Kahn's
+/// algorithm. `v_dep_k` maps a page to the pages that must come after it,
+/// `k_dep_v` a page to the pages that must come before it; rules between
+/// pages outside the update are ignored.
+pub fn topological_sort(
+    v_dep_k: &HashMap<i32, HashSet<i32>>,
+    k_dep_v: &HashMap<i32, HashSet<i32>>,
+    update: &[i32],
+) -> Vec<i32> {
+    // compute in-degree of each node, i.e., how many nodes it depends on
+    let mut in_degrees = update
+        .iter()
+        .map(|&k| match k_dep_v.get(&k) {
+            Some(v) => (k, v.iter().filter(|vs| update.contains(vs)).count() as i32),
+            None => (k, 0),
+        })
+        .collect::<HashMap<i32, i32>>();
+
+    // a node can be put into the sorted vector if it has a 0 in-degree
+    let mut queue = in_degrees
+        .iter()
+        .filter(|(_, &v)| v == 0)
+        .map(|(&k, _)| k)
+        .collect::<Vec<i32>>();
+
+    let mut sorted = vec![];
+    while let Some(cur_node) = queue.pop() {
+        sorted.push(cur_node);
+        if let Some(neighbours) = v_dep_k.get(&cur_node) {
+            for neighbour in neighbours.iter().filter(|n| update.contains(n)) {
+                trace!(
+                    2,
+                    "rule {}|{}: {} is placed before {}",
+                    cur_node,
+                    neighbour,
+                    cur_node,
+                    neighbour
+                );
+                in_degrees.entry(*neighbour).and_modify(|v| *v -= 1);
+                if in_degrees[neighbour] == 0 {
+                    queue.push(*neighbour);
+                }
+            }
+        }
+    }
+
+    sorted
+}
+
+pub fn compute(input: &Input) -> (i32, i32) {
+    let mut k_deps_v: HashMap<i32, HashSet<i32>> = HashMap::new();
+    let mut v_deps_k: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+    for &(x, y) in input.rules.iter() {
+        // x needs to be printed before y, y depends on x
+        v_deps_k.entry(x).or_default().insert(y);
+        k_deps_v.entry(y).or_default().insert(x);
+    }
+
+    let mut p1 = 0;
+    let mut p2 = 0;
+    for vec in input.updates.iter() {
+        let sorted = topological_sort(&v_deps_k, &k_deps_v, vec);
+        if sorted.eq(vec) {
+            trace!(1, "update {:?} is in order", vec);
+            p1 += vec[vec.len() / 2];
+        } else {
+            trace!(
+                1,
+                "update {:?} is out of order, sorted as {:?}",
+                vec,
+                sorted
+            );
+            p2 += sorted[sorted.len() / 2];
+        }
+    }
+
+    (p1, p2)
+}
+
+pub fn simple_compute(input: &Input) -> (i32, i32) {
+    // orders: key needs to print before values
+    let mut orders: HashMap<i32, HashSet<i32>> = HashMap::new();
+
+    for &(x, y) in input.rules.iter() {
+        orders.entry(x).or_default().insert(y);
+    }
+
+    let mut p1 = 0;
+    let mut p2 = 0;
+    for update in input.updates.iter() {
+        let mut vec = update.clone();
+        if vec.is_sorted_by(|a, b| !orders.contains_key(b) || !orders[b].contains(a)) {
+            trace!(1, "update {:?} is in order", vec);
+            p1 += vec[vec.len() / 2];
+        } else {
+            trace!(
+                1,
+                "update {:?} is out of order: {}",
+                vec,
+                vec.windows(2)
+                    .find(|w| orders.get(&w[1]).is_some_and(|o| o.contains(&w[0])))
+                    .map_or(String::new(), |w| format!(
+                        "rule {}|{} is broken",
+                        w[1], w[0]
+                    ))
+            );
+            vec.sort_by(|a, b| {
+                if orders.get(a).is_some_and(|o| o.contains(b)) {
+                    trace!(2, "rule {}|{}: {} is placed before {}", a, b, a, b);
+                    Ordering::Less
+                } else if orders.get(b).is_some_and(|o| o.contains(a)) {
+                    trace!(2, "rule {}|{}: {} is placed before {}", b, a, b, a);
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            });
+            trace!(1, "sorted as {:?}", vec);
+            p2 += vec[vec.len() / 2];
+        }
+    }
+
+    (p1, p2)
+}
+
+/// Checks each update with a comparator built from the rules.
+pub struct SimpleCompute;
+
+impl Solution for SimpleCompute {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "simple";
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        Ok(simple_compute(input).0.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        Ok(simple_compute(input).1.into())
+    }
+}
+
+/// Orders each update with a topological sort of the rules restricted to its pages.
+pub struct TopologicalSort;
+
+impl Solution for TopologicalSort {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "topological";
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        Ok(compute(input).0.into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        Ok(compute(input).1.into())
+    }
+}
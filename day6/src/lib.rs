@@ -0,0 +1,226 @@
+use common::geometry::Direction;
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+use std::collections::HashSet;
+
+pub const DAY: u8 = 6;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/inputs.txt");
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Position of the guard, `^`.
+///
+/// # Panics
+///
+/// When the map has no guard.
+pub fn find_start(m: &Grid<char>) -> (usize, usize) {
+    m.find(&'^').unwrap()
+}
+
+/// Number of distinct cells the guard visits from `start` (row, column and
+/// heading) until leaving the map.
+///
+/// # Panics
+///
+/// When the guard walks in a loop and never leaves the map.
+pub fn out_of_grid(start: &(usize, usize, Direction), m: &Grid<char>) -> usize {
+    let (mut cur_x, mut cur_y, mut cur_dir) = start;
+    // cells with their heading, like the 2D array of `out_of_grid2`, so
+    // noticing a loop costs no more than recording the walk
+    let mut hits = HashSet::new();
+    loop {
+        // back on a cell with the same heading: the walk repeats forever
+        assert!(
+            hits.insert((cur_x, cur_y, cur_dir)),
+            "the guard never leaves the map"
+        );
+
+        let Some((new_x, new_y)) = m.step((cur_x, cur_y), cur_dir) else {
+            return hits
+                .iter()
+                .map(|&(x, y, _)| (x, y))
+                .collect::<HashSet<_>>()
+                .len();
+        };
+        if m[(new_x, new_y)] == '#' {
+            trace!(2, "the guard turns right at {:?}", (cur_x, cur_y));
+            cur_dir = cur_dir.turn_right();
+        } else {
+            (cur_x, cur_y) = (new_x, new_y);
+        }
+    }
+}
+
+/// Like `out_of_grid`, but `None` when the guard walks in a loop.
+pub fn out_of_grid2(start: &(usize, usize, Direction), m: &Grid<char>) -> Option<i32> {
+    let (mut cur_x, mut cur_y, mut cur_dir) = start;
+    // optimisation: using a 2D array rather than a hashset
+    let mut hits = Grid::new(m.width(), m.height(), [false; 4]);
+    // let mut hits = HashSet::new();
+    loop {
+        // if hits.contains(&(cur_x, cur_y, cur_dir)) {
+        //     return None;
+        // }
+        // hits.insert((cur_x, cur_y, cur_dir));
+        if hits[(cur_x, cur_y)][cur_dir.index()] {
+            return None;
+        }
+        hits[(cur_x, cur_y)][cur_dir.index()] = true;
+
+        let Some((new_x, new_y)) = m.step((cur_x, cur_y), cur_dir) else {
+            // return Some(
+            //     hits.iter()
+            //         .map(|(i, j, _)| (*i, *j))
+            //         .collect::<HashSet<(i32, i32)>>(),
+            // );
+            return Some(hits.iter().filter(|col| col.iter().any(|&b| b)).count() as i32);
+        };
+        if m[(new_x, new_y)] == '#' {
+            cur_dir = cur_dir.turn_right();
+        } else {
+            (cur_x, cur_y) = (new_x, new_y);
+        }
+    }
+}
+
+pub fn part1(map: &Grid<char>) -> u32 {
+    let mut m = map.clone();
+    let start = find_start(&m);
+    m[start] = '.';
+
+    out_of_grid(&(start.0, start.1, Direction::Up), &m) as u32
+}
+
+/// Same as `part1`, but walking with the 2D array of `out_of_grid2`.
+pub fn part1_bool_array(map: &Grid<char>) -> u32 {
+    let mut m = map.clone();
+    let start = find_start(&m);
+    m[start] = '.';
+
+    out_of_grid2(&(start.0, start.1, Direction::Up), &m).expect("the guard never leaves the map")
+        as u32
+}
+
+/// Cells the guard walks through before leaving the map, in order.
+pub fn guard_path(map: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut m = map.clone();
+    let start = find_start(&m);
+    m[start] = '.';
+
+    let (mut cur, mut cur_dir) = (start, Direction::Up);
+    let mut seen = Grid::new(m.width(), m.height(), [false; 4]);
+    let mut path = vec![];
+    // stop if the guard loops, the path is complete by then
+    while !seen[cur][cur_dir.index()] {
+        if !seen[cur].iter().any(|&b| b) {
+            path.push(cur);
+        }
+        seen[cur][cur_dir.index()] = true;
+        let Some(new) = m.step(cur, cur_dir) else {
+            break;
+        };
+        if m[new] == '#' {
+            cur_dir = cur_dir.turn_right();
+        } else {
+            cur = new;
+        }
+    }
+    path
+}
+
+pub fn part2(map: &Grid<char>) -> u32 {
+    loop_obstacles(map).len() as u32
+}
+
+/// Cells where one more obstacle would trap the guard in a loop.
+///
+/// # Panics
+///
+/// When the guard already walks in a loop and never leaves the map.
+pub fn loop_obstacles(map: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut m = map.clone();
+    let start = find_start(&m);
+    m[start] = '.';
+
+    let mut cur_dir = Direction::Up;
+    let (mut cur_x, mut cur_y) = start;
+
+    // let mut visited = HashSet::new();
+    // visited.insert((cur_x, cur_y));
+    // headings the guard walked each cell with, to notice a loop
+    let mut visited = Grid::new(m.width(), m.height(), [false; 4]);
+
+    let mut res = vec![];
+    loop {
+        assert!(
+            !visited[(cur_x, cur_y)][cur_dir.index()],
+            "the guard never leaves the map"
+        );
+        visited[(cur_x, cur_y)][cur_dir.index()] = true;
+        let Some((new_x, new_y)) = m.step((cur_x, cur_y), cur_dir) else {
+            break;
+        };
+        if m[(new_x, new_y)] == '#' {
+            cur_dir = cur_dir.turn_right();
+        } else {
+            if (new_x, new_y) != start && !visited[(new_x, new_y)].iter().any(|&b| b) {
+                m[(new_x, new_y)] = '#';
+                if out_of_grid2(&(cur_x, cur_y, cur_dir), &m).is_none() {
+                    trace!(1, "an obstacle at {:?} traps the guard", (new_x, new_y));
+                    res.push((new_x, new_y));
+                }
+                m[(new_x, new_y)] = '.';
+            }
+            (cur_x, cur_y) = (new_x, new_y);
+        }
+    }
+    res
+}
+
+/// Part 1 tracks the visited positions in a `HashSet`, see `out_of_grid`.
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "hashset";
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
+
+/// Part 1 tracks the visited positions in a 2D array, see `out_of_grid2`.
+pub struct Day6BoolArray;
+
+impl Solution for Day6BoolArray {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "bool-array";
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part1_bool_array(input).into())
+    }
+
+    fn part2(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
@@ -0,0 +1,255 @@
+use common::parse::{self, ParseError};
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+
+pub const DAY: u8 = 7;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/inputs.txt");
+pub struct Equation {
+    pub value: i64,
+    pub numbers: Vec<i64>,
+}
+
+/// One calibration equation per non-blank line.
+pub struct Input {
+    pub equations: Vec<Equation>,
+}
+
+/// `v || h`, the digits of `h` written after those of `v` through a string,
+/// unless the result overflows.
+fn concat(v: i64, h: i64) -> Option<i64> {
+    [v.to_string(), h.to_string()].concat().parse().ok()
+}
+
+fn digits(h: i64) -> u32 {
+    h.checked_ilog10().unwrap_or(0) + 1
+}
+
+impl Equation {
+    /// Whether `+` and `*` between `v` and `nums`, evaluated left to right,
+    /// can give the value. Start with `v` the first number and `nums` the rest.
+    /// Operations that overflow cannot give the value and are skipped.
+    pub fn is_valid(&self, v: i64, nums: &[i64]) -> bool {
+        if nums.is_empty() {
+            return self.value == v;
+        }
+        let (h, t) = (nums[0], &nums[1..]);
+        v.checked_mul(h).is_some_and(|v| self.is_valid(v, t))
+            || v.checked_add(h).is_some_and(|v| self.is_valid(v, t))
+    }
+
+    /// `is_valid` with the concatenation operator `||` as well.
+    pub fn is_valid2(&self, v: i64, nums: &[i64]) -> bool {
+        if nums.is_empty() {
+            return self.value == v;
+        }
+        let (h, t) = (nums[0], &nums[1..]);
+        v.checked_mul(h).is_some_and(|v| self.is_valid2(v, t))
+            || v.checked_add(h).is_some_and(|v| self.is_valid2(v, t))
+            || concat(v, h).is_some_and(|v| self.is_valid2(v, t))
+    }
+
+    /// Same answer as `is_valid2`, found by undoing the operators from the
+    /// value: start with `v` the value and `revert_nums` the numbers in
+    /// reverse order. Each step prunes the operators that cannot apply, e.g.
+    /// `*` when `v` is not a multiple of the number.
+    pub fn is_valid2_revert(&self, v: i64, revert_nums: &[i64]) -> bool {
+        if revert_nums.len() == 1 {
+            return v == revert_nums[0];
+        }
+        let (h, t) = (revert_nums[0], &revert_nums[1..]);
+        let sub_opt = if v - h >= 0 {
+            self.is_valid2_revert(v - h, t)
+        } else {
+            false
+        };
+        // `None` when dividing by 0
+        let div_opt = if v.checked_rem(h) == Some(0) {
+            self.is_valid2_revert(v / h, t)
+        } else {
+            false
+        };
+
+        let concat_opt = 10_i64
+            .checked_pow(digits(h))
+            .is_some_and(|p| v % p == h && self.is_valid2_revert((v - h) / p, t));
+        sub_opt || div_opt || concat_opt
+    }
+
+    /// The first combination of operators making the equation true, written
+    /// out as `190 = 10 * 19`, for tracing. Kept apart from the searches so
+    /// they do not pay for it, and only run once they found the equation true.
+    fn explain(&self, with_concat: bool) -> Option<String> {
+        fn search(
+            target: i64,
+            v: i64,
+            nums: &[i64],
+            with_concat: bool,
+            ops: &mut Vec<&str>,
+        ) -> bool {
+            let Some((&h, t)) = nums.split_first() else {
+                return v == target;
+            };
+            let mut candidates = vec![("*", v.checked_mul(h)), ("+", v.checked_add(h))];
+            if with_concat {
+                candidates.push(("||", concat(v, h)));
+            }
+            candidates.into_iter().any(|(op, next)| {
+                next.is_some_and(|next| {
+                    ops.push(op);
+                    search(target, next, t, with_concat, ops) || {
+                        ops.pop();
+                        false
+                    }
+                })
+            })
+        }
+
+        let mut ops = vec![];
+        if !search(
+            self.value,
+            self.numbers[0],
+            &self.numbers[1..],
+            with_concat,
+            &mut ops,
+        ) {
+            return None;
+        }
+        let mut text = format!("{} = {}", self.value, self.numbers[0]);
+        for (op, n) in ops.iter().zip(&self.numbers[1..]) {
+            text += &format!(" {} {}", op, n);
+        }
+        Some(text)
+    }
+
+    /// Trace why the equation is or is not counted, `valid` being what the
+    /// solver found.
+    fn trace(&self, concat: bool, valid: bool) {
+        // no second search for an equation already known false
+        match valid.then(|| self.explain(concat)).flatten() {
+            Some(text) => trace!(1, "{}", text),
+            None => trace!(2, "{}: {:?} cannot be made true", self.value, self.numbers),
+        }
+    }
+}
+pub fn parse(input: &str) -> Result<Input, ParseError> {
+    let equations = parse::non_blank_lines(input)
+        .map(|l| {
+            let (value, numbers) = l.pair(':', "an equation `VALUE: NUMBERS`")?;
+            let numbers = numbers
+                .split_whitespace()
+                .map(|x| l.number::<i64>(x))
+                .collect::<Result<Vec<i64>, _>>()?;
+            if numbers.is_empty() {
+                return Err(l.end_of_line("at least one number"));
+            }
+            Ok(Equation {
+                value: l.number::<i64>(value)?,
+                numbers,
+            })
+        })
+        .collect::<Result<Vec<Equation>, _>>()?;
+    Ok(Input { equations })
+}
+
+pub fn part1(input: &Input) -> i64 {
+    input
+        .equations
+        .iter()
+        .map(|eqt| {
+            let valid = eqt.is_valid(eqt.numbers[0], &eqt.numbers[1..]);
+            if trace::enabled(1) {
+                eqt.trace(false, valid);
+            }
+            if valid {
+                eqt.value
+            } else {
+                0
+            }
+        })
+        .sum::<i64>()
+}
+
+pub fn part2(input: &Input) -> i64 {
+    input
+        .equations
+        .iter()
+        .map(|eqt| {
+            let mut nums_copy = eqt.numbers.clone();
+            nums_copy.reverse();
+            let valid = eqt.is_valid2_revert(eqt.value, &nums_copy);
+            if trace::enabled(1) {
+                eqt.trace(true, valid);
+            }
+            if valid {
+                eqt.value
+            } else {
+                0
+            }
+        })
+        .sum::<i64>()
+}
+
+/// Same as `part2`, but searching forwards from the first number and
+/// concatenating through strings rather than undoing each operator from the value.
+/// Combinations that overflow an `i64` are skipped.
+pub fn part2_forward(input: &Input) -> i64 {
+    input
+        .equations
+        .iter()
+        .map(|eqt| {
+            let valid = eqt.is_valid2(eqt.numbers[0], &eqt.numbers[1..]);
+            if trace::enabled(1) {
+                eqt.trace(true, valid);
+            }
+            if valid {
+                eqt.value
+            } else {
+                0
+            }
+        })
+        .sum::<i64>()
+}
+
+/// Part 2 undoes the operators from the expected value, see `is_valid2_revert`.
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "reverse";
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}
+
+/// Part 2 tries every operator from the first number, see `is_valid2`.
+pub struct Day7Forward;
+
+impl Solution for Day7Forward {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    const VARIANT: &'static str = "forward";
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Input) -> Result<Answer, SolveError> {
+        Ok(part2_forward(input).into())
+    }
+}
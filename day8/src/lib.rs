@@ -0,0 +1,109 @@
+use common::geometry::Point;
+use common::grid::Grid;
+use common::parse::ParseError;
+use common::solution::{Answer, Solution, SolveError};
+use common::trace;
+use std::collections::{HashMap, HashSet};
+
+pub const DAY: u8 = 8;
+pub const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/test/inputs.txt");
+
+pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Positions of the antennas of each frequency, every cell but `.`.
+pub fn antennas(grid: &Grid<char>) -> HashMap<char, Vec<Point>> {
+    let mut char_to_pos: HashMap<char, Vec<Point>> = HashMap::new();
+    grid.indexed_iter()
+        .filter(|(_, &c)| c != '.')
+        .for_each(|(pos, c)| {
+            char_to_pos
+                .entry(*c)
+                .or_default()
+                .push(Point::from_index(pos).unwrap());
+        });
+    char_to_pos
+}
+
+/// Antinodes of each frequency. With `resonant` harmonics every grid point a
+/// whole number of pair distances from the antennas counts (part 2), otherwise
+/// only the two points at the pair distance (part 1).
+pub fn antinodes(grid: &Grid<char>, resonant: bool) -> HashMap<char, HashSet<Point>> {
+    let char_to_pos = antennas(grid);
+
+    let mut res: HashMap<char, HashSet<Point>> = HashMap::new();
+    for (&freq, pos_pairs) in char_to_pos.iter() {
+        let res = res.entry(freq).or_default();
+        for i in 0..pos_pairs.len() {
+            for j in i + 1..pos_pairs.len() {
+                let p1 = pos_pairs[i];
+                let p2 = pos_pairs[j];
+                trace!(2, "antennas {} at {:?} and {:?}", freq, p1, p2);
+                if !resonant {
+                    let diff = p2 - p1;
+                    for new in [p1 - diff, p2 + diff] {
+                        if grid.contains(new) {
+                            res.insert(new);
+                        }
+                    }
+                    continue;
+                }
+
+                let diff = p2 - p1;
+                res.insert(p1);
+                res.insert(p2);
+
+                let mut new1 = p1 - diff;
+                while grid.contains(new1) {
+                    res.insert(new1);
+                    new1 -= diff;
+                }
+
+                let mut new2 = p2 + diff;
+                while grid.contains(new2) {
+                    res.insert(new2);
+                    new2 += diff;
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Distinct antinode locations, whatever their frequency.
+fn count(antinodes: HashMap<char, HashSet<Point>>) -> usize {
+    antinodes
+        .into_values()
+        .flatten()
+        .collect::<HashSet<_>>()
+        .len()
+}
+
+pub fn part1(grid: &Grid<char>) -> usize {
+    count(antinodes(grid, false))
+}
+
+pub fn part2(grid: &Grid<char>) -> usize {
+    count(antinodes(grid, true))
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = DAY;
+    const DEFAULT_INPUT: &'static str = DEFAULT_INPUT;
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part1(input).into())
+    }
+
+    fn part2(input: &Grid<char>) -> Result<Answer, SolveError> {
+        Ok(part2(input).into())
+    }
+}